@@ -2,11 +2,10 @@
 extern crate slugmin;
 extern crate test;
 
-use slugmin::slugify;
+use slugmin::{slugify, slugify_normal};
 
 #[bench]
 fn bench_slug(b: &mut test::Bencher) {
-    use slug::slugify;
     b.iter(|| {
         test::black_box(slugify(test::black_box("My test Slug!!")));
         test::black_box(slugify(test::black_box("Test Slug2!!")));
@@ -17,19 +16,32 @@ fn bench_slug(b: &mut test::Bencher) {
 
 #[bench]
 fn bench_slug_normal(b: &mut test::Bencher) {
-    use slug::slugify;
     b.iter(|| {
-        test::black_box(slugify(test::black_box("My test Slug!!")));
-        test::black_box(slugify(test::black_box("Some other.. slug")));
-        test::black_box(slugify(test::black_box("CAPSLOCK IS AN AUTOPILOT FOR COOL")));
+        test::black_box(slugify_normal(test::black_box("My test Slug!!"), false));
+        test::black_box(slugify_normal(test::black_box("Some other.. slug"), false));
+        test::black_box(slugify_normal(test::black_box("CAPSLOCK IS AN AUTOPILOT FOR COOL"), true));
     })
 }
 
 #[bench]
 fn bench_unicode(b: &mut test::Bencher) {
-    use slug::slugify;
     b.iter(|| {
         test::black_box(slugify(test::black_box("Æúűűűű--cool?")));
         test::black_box(slugify(test::black_box("മലയാലമ്げんまい茶??")));
     })
 }
+
+#[bench]
+fn bench_clean_slug(b: &mut test::Bencher) {
+    b.iter(|| {
+        test::black_box(slugify(test::black_box("my-test-slug")));
+        test::black_box(slugify(test::black_box("a-much-longer-slug-which-was-normalized-before-2024")));
+        test::black_box(slugify(test::black_box("capslock-is-an-autopilot-for-cool")));
+    })
+}
+
+#[bench]
+fn bench_long_clean(b: &mut test::Bencher) {
+    let s = "abc-def-".repeat(200) + "x";
+    b.iter(|| test::black_box(slugify(test::black_box(&s))))
+}
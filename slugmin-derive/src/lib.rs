@@ -0,0 +1,144 @@
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitInt, LitStr};
+
+/// Generate a `fill_<field>` method setting every field marked with `#[slug(from = "..")]`
+/// to the slug of the named field
+///
+/// The source field can be anything implementing `AsRef<str>`, the slug is made with
+/// `slugmin::slugify`, or with `slugmin::slugify_with` cut to `max_length` bytes at a word
+/// boundary when `#[slug(max_length = ..)]` is given.
+///
+/// ```rust
+/// use slugmin::Slugify;
+///
+/// #[derive(Slugify)]
+/// struct Post {
+///     title: String,
+///     #[slug(from = "title")]
+///     slug: String,
+///     #[slug(from = "title", max_length = 10)]
+///     short_slug: String,
+/// }
+///
+/// let mut post = Post {
+///     title: "Hello, World! A First Post".to_string(),
+///     slug: String::new(),
+///     short_slug: String::new(),
+/// };
+/// post.fill_slug();
+/// post.fill_short_slug();
+/// assert_eq!(post.slug, "hello-world-a-first-post");
+/// assert_eq!(post.short_slug, "hello");
+/// ```
+///
+/// The source field must exist:
+///
+/// ```compile_fail
+/// #[derive(slugmin::Slugify)]
+/// struct Post {
+///     #[slug(from = "title")]
+///     slug: String,
+/// }
+/// ```
+#[proc_macro_derive(Slugify, attributes(slug))]
+pub fn derive_slugify(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+// A field filled with the slug of another one
+struct SlugField {
+    field: Ident,
+    from: Ident,
+    max_length: Option<usize>,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<impl Into<TokenStream>> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(input, "Slugify needs named fields")),
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "Slugify can only be derived for structs",
+            ))
+        }
+    };
+    let names: Vec<&Ident> = fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .collect();
+    let mut slug_fields = Vec::new();
+    for field in fields {
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("slug"))
+        {
+            let mut from = None;
+            let mut max_length = None;
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("from") {
+                    from = Some(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
+                } else if meta.path.is_ident("max_length") {
+                    max_length = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `from` or `max_length`"))
+                }
+            })?;
+            let from = from.ok_or_else(|| Error::new_spanned(attr, "missing `from = \"..\"`"))?;
+            if !names.iter().any(|name| *name == from.value().as_str()) {
+                let message = format!("no field `{}` to slugify", from.value());
+                return Err(Error::new_spanned(&from, message));
+            }
+            slug_fields.push(SlugField {
+                // Only named fields can be listed
+                field: field.ident.clone().expect("named field"),
+                from: Ident::new(&from.value(), from.span()),
+                max_length,
+            });
+        }
+    }
+
+    let methods = slug_fields.iter().map(|slug_field| {
+        let SlugField {
+            field,
+            from,
+            max_length,
+        } = slug_field;
+        let method = format_ident!("fill_{}", field);
+        let doc = format!("Set `{}` to the slug of `{}`", field, from);
+        let slug = match max_length {
+            Some(max_length) => quote! {
+                ::slugmin::slugify_with(
+                    &self.#from,
+                    &::slugmin::SlugOptions::new().max_length(#max_length),
+                )
+            },
+            None => quote! { ::slugmin::slugify(&self.#from) },
+        };
+        quote! {
+            #[doc = #doc]
+            pub fn #method(&mut self) {
+                self.#field = #slug;
+            }
+        }
+    });
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
@@ -0,0 +1,204 @@
+use crate::chars::is_combining_mark;
+use crate::percent_encode;
+use std::collections::HashMap;
+
+/// Convert a Markdown heading to the anchor GitHub generates for it
+///
+/// The heading is lowercased, every space becomes `-` (runs are not collapsed), letters,
+/// numbers, combining marks, `-` and `_` are kept and everything else is removed. Unlike
+/// `slugify`, non ascii letters are kept as they are.
+///
+/// ```rust
+/// use slugmin::github_anchor;
+///
+/// assert_eq!(github_anchor("Hello, World!"), "hello-world");
+/// assert_eq!(github_anchor("🎉 Release Notes"), "-release-notes");
+/// assert_eq!(github_anchor("Café au Lait"), "café-au-lait");
+/// assert_eq!(github_anchor("snake_case & kebab-case"), "snake_case--kebab-case");
+/// assert_eq!(github_anchor("Version 1.2.3"), "version-123");
+/// ```
+pub fn github_anchor<S: AsRef<str>>(s: S) -> String {
+    _github_anchor(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _github_anchor(s: &str) -> String {
+    let mut anchor = String::with_capacity(s.len());
+    for c in s.chars().flat_map(char::to_lowercase) {
+        if c == ' ' {
+            anchor.push('-');
+        } else if c.is_alphanumeric() || c == '-' || c == '_' || is_combining_mark(c) {
+            anchor.push(c);
+        }
+    }
+    anchor
+}
+
+/// Convert a Markdown heading to a GitHub anchor which is unique within a document
+///
+/// `occurrences` holds the anchors generated so far for the document. Repeated headings get
+/// a `-1`, `-2`, ... suffix, skipping suffixes which are already taken, exactly like GitHub.
+///
+/// ```rust
+/// use slugmin::github_anchor_unique;
+/// use std::collections::HashMap;
+///
+/// let mut occurrences = HashMap::new();
+/// assert_eq!(github_anchor_unique("Usage", &mut occurrences), "usage");
+/// assert_eq!(github_anchor_unique("Usage", &mut occurrences), "usage-1");
+/// assert_eq!(github_anchor_unique("Usage 2", &mut occurrences), "usage-2");
+/// assert_eq!(github_anchor_unique("Usage", &mut occurrences), "usage-3");
+/// ```
+pub fn github_anchor_unique<S: AsRef<str>>(
+    s: S,
+    occurrences: &mut HashMap<String, usize>,
+) -> String {
+    unique_anchor(_github_anchor(s.as_ref()), occurrences)
+}
+
+/// Generates GitHub anchors which are unique within a single document
+///
+/// The first occurrence of a heading gets the plain anchor, the following ones get `-1`,
+/// `-2`, ... appended. Use a new generator for every document.
+///
+/// ```rust
+/// use slugmin::AnchorGenerator;
+///
+/// let mut anchors = AnchorGenerator::new();
+/// assert_eq!(anchors.push("H"), "h");
+/// assert_eq!(anchors.push("H"), "h-1");
+/// assert_eq!(anchors.push("h"), "h-2");
+/// assert_eq!(anchors.push("Other heading"), "other-heading");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AnchorGenerator {
+    occurrences: HashMap<String, usize>,
+}
+
+impl AnchorGenerator {
+    /// Generator for a new document, no anchor is taken yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the unique anchor for the next heading of the document
+    pub fn push<S: AsRef<str>>(&mut self, heading: S) -> String {
+        github_anchor_unique(heading, &mut self.occurrences)
+    }
+}
+
+/// Convert all headings of a Markdown document to GitHub anchors which are unique within it
+///
+/// The anchors are in the order of `headings`, numbered like
+/// [`AnchorGenerator`](struct.AnchorGenerator.html) does.
+///
+/// ```rust
+/// use slugmin::anchors_for;
+///
+/// let headings = ["Usage", "Install", "Usage", "FAQ", "Usage"];
+/// assert_eq!(anchors_for(&headings), ["usage", "install", "usage-1", "faq", "usage-2"]);
+/// assert!(anchors_for::<&str>(&[]).is_empty());
+/// ```
+pub fn anchors_for<S: AsRef<str>>(headings: &[S]) -> Vec<String> {
+    let mut anchors = AnchorGenerator::new();
+    headings
+        .iter()
+        .map(|heading| anchors.push(heading))
+        .collect()
+}
+
+/// Convert a heading to the identifier Pandoc generates for it
+///
+/// Everything except letters, numbers, `_`, `-` and `.` is removed, whitespace runs become a
+/// single `-` and letters are lowercased. Then everything up to the first letter is removed,
+/// as identifiers can't start with a number or punctuation, and `section` is used when nothing
+/// is left. Like in Pandoc, non ascii letters are kept.
+///
+/// ```rust
+/// use slugmin::pandoc_identifier;
+///
+/// assert_eq!(pandoc_identifier("Heading identifiers in HTML"), "heading-identifiers-in-html");
+/// assert_eq!(pandoc_identifier("Dogs?--in *my* house?"), "dogs--in-my-house");
+/// assert_eq!(pandoc_identifier("[HTML], [S5], or [RTF]?"), "html-s5-or-rtf");
+/// assert_eq!(pandoc_identifier("1. Introduction"), "introduction");
+/// assert_eq!(pandoc_identifier("Maître d'hôtel"), "maître-dhôtel");
+/// assert_eq!(pandoc_identifier("33"), "section");
+/// assert_eq!(pandoc_identifier("!!!"), "section");
+/// ```
+pub fn pandoc_identifier<S: AsRef<str>>(s: S) -> String {
+    _pandoc_identifier(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _pandoc_identifier(s: &str) -> String {
+    let mut identifier = String::with_capacity(s.len());
+    let mut pending_dash = false;
+    for c in s.chars() {
+        if c.is_whitespace() {
+            pending_dash = !identifier.is_empty();
+        } else if c.is_alphanumeric() || c == '_' || c == '-' || c == '.' {
+            if pending_dash {
+                identifier.push('-');
+                pending_dash = false;
+            }
+            identifier.extend(c.to_lowercase());
+        }
+    }
+    match identifier.find(char::is_alphabetic) {
+        Some(start) => identifier.split_off(start),
+        None => "section".to_string(),
+    }
+}
+
+/// Convert a page section title to a MediaWiki (Wikipedia) link fragment
+///
+/// Case is preserved, leading and trailing whitespace is removed and runs of whitespace and
+/// underscores become a single `_`. Characters allowed in an URL fragment (ascii letters,
+/// digits and `-._~!$&'()*+,;=:@/?`) are kept, everything else is percent encoded as UTF-8.
+/// As `%` itself is encoded, this is not idempotent, use it on the title and not on an anchor.
+///
+/// ```rust
+/// use slugmin::wiki_anchor;
+///
+/// assert_eq!(wiki_anchor("Hello World!"), "Hello_World!");
+/// assert_eq!(wiki_anchor("  Early   life_and career "), "Early_life_and_career");
+/// assert_eq!(wiki_anchor("C# & .NET"), "C%23_&_.NET");
+/// assert_eq!(wiki_anchor("100% \"real\""), "100%25_%22real%22");
+/// assert_eq!(wiki_anchor("Café"), "Caf%C3%A9");
+/// ```
+pub fn wiki_anchor<S: AsRef<str>>(s: S) -> String {
+    _wiki_anchor(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _wiki_anchor(s: &str) -> String {
+    let mut anchor = String::with_capacity(s.len());
+    let mut pending_underscore = false;
+    for c in s.trim().chars() {
+        if c.is_whitespace() || c == '_' {
+            pending_underscore = true;
+            continue;
+        }
+        if pending_underscore {
+            anchor.push('_');
+            pending_underscore = false;
+        }
+        if c.is_ascii_alphanumeric() || "-.~!$&'()*+,;=:@/?".contains(c) {
+            anchor.push(c);
+        } else {
+            percent_encode(c, |b| anchor.push(b as char));
+        }
+    }
+    anchor
+}
+
+fn unique_anchor(anchor: String, occurrences: &mut HashMap<String, usize>) -> String {
+    let mut result = anchor.clone();
+    while occurrences.contains_key(&result) {
+        let count = occurrences.entry(anchor.clone()).or_insert(0);
+        *count += 1;
+        result = format!("{}-{}", anchor, count);
+    }
+    occurrences.insert(result.clone(), 0);
+    result
+}
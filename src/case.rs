@@ -0,0 +1,226 @@
+use crate::slugify;
+
+/// Convert any unicode string to space separated Title Case words
+///
+/// Words are the same as in `slugify` output. Words listed in `acronyms` (compared
+/// case-insensitively) are written fully uppercase.
+///
+/// ```rust
+/// use slugmin::titleize;
+///
+/// assert_eq!(titleize("nasa api key", &[]), "Nasa Api Key");
+/// assert_eq!(titleize("nasa api key", &["nasa", "api"]), "NASA API Key");
+/// assert_eq!(titleize("the QUICK brown-fox", &[]), "The Quick Brown Fox");
+/// ```
+pub fn titleize<S: AsRef<str>>(s: S, acronyms: &[&str]) -> String {
+    title_words(s.as_ref(), acronyms, ' ')
+}
+
+/// Convert any unicode string to Train-Case, title cased words joined with `-`
+///
+/// Words listed in `acronyms` (compared case-insensitively) are written fully uppercase.
+///
+/// ```rust
+/// use slugmin::slugify_train_case;
+///
+/// assert_eq!(slugify_train_case("nasa api key", &[]), "Nasa-Api-Key");
+/// assert_eq!(slugify_train_case("nasa-api-key", &["nasa", "api"]), "NASA-API-Key");
+/// assert_eq!(slugify_train_case("NASA API key", &["Nasa", "API"]), "NASA-API-Key");
+/// assert_eq!(slugify_train_case("Æúű--cool?", &[]), "Aeuu-Cool");
+/// ```
+pub fn slugify_train_case<S: AsRef<str>>(s: S, acronyms: &[&str]) -> String {
+    title_words(s.as_ref(), acronyms, '-')
+}
+
+/// Convert any unicode string to a slug whose first letter is uppercase, like `Hello-world`
+///
+/// Leading digits are left as they are, the first letter after them is uppercased.
+///
+/// ```rust
+/// use slugmin::slugify_sentence_case;
+///
+/// assert_eq!(slugify_sentence_case("hello world"), "Hello-world");
+/// assert_eq!(slugify_sentence_case("über alles"), "Uber-alles");
+/// assert_eq!(slugify_sentence_case("HELLO World"), "Hello-world");
+/// assert_eq!(slugify_sentence_case("3 little pigs"), "3-Little-pigs");
+/// assert_eq!(slugify_sentence_case("!!!"), "");
+/// ```
+pub fn slugify_sentence_case<S: AsRef<str>>(s: S) -> String {
+    let mut slug = slugify(s);
+    if let Some(first) = slug.find(|c: char| c.is_ascii_alphabetic()) {
+        slug[first..=first].make_ascii_uppercase();
+    }
+    slug
+}
+
+/// Convert any unicode string to its slug and a Title Case display name with the same words
+///
+/// ```rust
+/// use slugmin::slug_and_title;
+///
+/// assert_eq!(
+///     slug_and_title("the QUICK brown-fox"),
+///     ("the-quick-brown-fox".to_string(), "The Quick Brown Fox".to_string())
+/// );
+/// assert_eq!(slug_and_title("Æúű--cool?"), ("aeuu-cool".to_string(), "Aeuu Cool".to_string()));
+/// assert_eq!(slug_and_title("!!!"), (String::new(), String::new()));
+/// ```
+pub fn slug_and_title<S: AsRef<str>>(s: S) -> (String, String) {
+    let slug = slugify(s);
+    let title = slug_title_words(&slug, &[], ' ');
+    (slug, title)
+}
+
+/// Convert any unicode string to a slug which is safe on case-insensitive file systems
+///
+/// The string is lowercased before the transliteration, as deunicode transliterates some
+/// letters differently in each case (`Є` is `E` but `є` is `ie`). So strings with the same
+/// `to_lowercase()` always give the same slug and `File` and `file` collide here and not later
+/// on the file system. The original case can't be restored from the slug.
+///
+/// ```rust
+/// use slugmin::slugify_ci_safe;
+///
+/// assert_eq!(slugify_ci_safe("File"), slugify_ci_safe("file"));
+/// assert_eq!(slugify_ci_safe("README.md"), "readme-md");
+/// assert_eq!(slugify_ci_safe("ÉTÉ ΣΟΦΊΑ STRAẞE"), slugify_ci_safe("été σοφία straße"));
+/// assert_eq!(slugify_ci_safe("ǅemal"), slugify_ci_safe("ǆemal"));
+/// assert_eq!(slugify_ci_safe("Єва"), slugify_ci_safe("єва"));
+/// ```
+pub fn slugify_ci_safe<S: AsRef<str>>(s: S) -> String {
+    slugify(s.as_ref().to_lowercase())
+}
+
+fn title_words(s: &str, acronyms: &[&str], separator: char) -> String {
+    slug_title_words(&slugify(s), acronyms, separator)
+}
+
+fn slug_title_words(slug: &str, acronyms: &[&str], separator: char) -> String {
+    let mut result = String::with_capacity(slug.len());
+    for word in slug.split('-') {
+        if !result.is_empty() {
+            result.push(separator);
+        }
+        push_title_word(&mut result, word, acronyms);
+    }
+    result
+}
+
+fn push_title_word(result: &mut String, word: &str, acronyms: &[&str]) {
+    if acronyms
+        .iter()
+        .any(|acronym| acronym.eq_ignore_ascii_case(word))
+    {
+        result.push_str(&word.to_ascii_uppercase());
+    } else {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            result.push(first.to_ascii_uppercase());
+            result.push_str(chars.as_str());
+        }
+    }
+}
+
+/// Output styles of [`restyle`](fn.restyle.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CaseStyle {
+    /// `myPost2`
+    Camel,
+    /// `MyPost2`
+    Pascal,
+    /// `my_post_2`
+    Snake,
+    /// `MY_POST_2`
+    ScreamingSnake,
+    /// `my-post-2`
+    Kebab,
+    /// `My-Post-2`
+    Train,
+}
+
+/// Write an existing slug in another style, like `my-post-2` as `myPost2`
+///
+/// The slug is split into words at `-` and `_`, so snake_case works as input too, and the
+/// words are lowercased before they are written in `style`. Nothing else is slugified, the
+/// original title is not needed.
+///
+/// ```rust
+/// use slugmin::{restyle, CaseStyle};
+///
+/// assert_eq!(restyle("my-post-2", CaseStyle::Camel), "myPost2");
+/// assert_eq!(restyle("my-post-2", CaseStyle::Pascal), "MyPost2");
+/// assert_eq!(restyle("my-post-2", CaseStyle::Snake), "my_post_2");
+/// assert_eq!(restyle("my-post-2", CaseStyle::ScreamingSnake), "MY_POST_2");
+/// assert_eq!(restyle("my-post-2", CaseStyle::Kebab), "my-post-2");
+/// assert_eq!(restyle("my-post-2", CaseStyle::Train), "My-Post-2");
+/// assert_eq!(restyle("MY_POST__2", CaseStyle::Kebab), "my-post-2");
+/// assert_eq!(restyle("", CaseStyle::Camel), "");
+/// ```
+pub fn restyle<S: AsRef<str>>(slug: S, style: CaseStyle) -> String {
+    _restyle(slug.as_ref(), style)
+}
+
+// avoid unnecessary monomorphizations
+fn _restyle(slug: &str, style: CaseStyle) -> String {
+    let separator = match style {
+        CaseStyle::Camel | CaseStyle::Pascal => None,
+        CaseStyle::Snake | CaseStyle::ScreamingSnake => Some('_'),
+        CaseStyle::Kebab | CaseStyle::Train => Some('-'),
+    };
+    let mut result = String::with_capacity(slug.len());
+    for word in slug.split(['-', '_']).filter(|word| !word.is_empty()) {
+        let word = word.to_ascii_lowercase();
+        let first = result.is_empty();
+        if let (Some(separator), false) = (separator, first) {
+            result.push(separator);
+        }
+        match style {
+            CaseStyle::Camel if first => result.push_str(&word),
+            CaseStyle::Camel | CaseStyle::Pascal | CaseStyle::Train => {
+                push_title_word(&mut result, &word, &[])
+            }
+            CaseStyle::ScreamingSnake => result.push_str(&word.to_ascii_uppercase()),
+            CaseStyle::Snake | CaseStyle::Kebab => result.push_str(&word),
+        }
+    }
+    result
+}
+
+/// Convert a camelCase or PascalCase identifier to a slug, splitting words at case transitions
+///
+/// A new word starts at an uppercase letter following a lowercase letter or digit, and at the
+/// last uppercase letter of an uppercase run followed by a lowercase letter (`HTTPResponse`).
+/// Underscores are word boundaries too, so mixed conventions are split cleanly.
+///
+/// ```rust
+/// use slugmin::slugify_camel;
+///
+/// assert_eq!(slugify_camel("myTestString"), "my-test-string");
+/// assert_eq!(slugify_camel("getHTTPResponse"), "get-http-response");
+/// assert_eq!(slugify_camel("getHTTP_Response_code"), "get-http-response-code");
+/// assert_eq!(slugify_camel("XML_parser_v2"), "xml-parser-v2");
+/// assert_eq!(slugify_camel("__private_Field"), "private-field");
+/// ```
+pub fn slugify_camel<S: AsRef<str>>(s: S) -> String {
+    _slugify_camel(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_camel(s: &str) -> String {
+    let mut split = String::with_capacity(s.len() + s.len() / 2);
+    let mut chars = s.chars().peekable();
+    let mut prev: Option<char> = None;
+    while let Some(c) = chars.next() {
+        if c.is_uppercase() {
+            if let Some(p) = prev {
+                let next_is_lower = chars.peek().is_some_and(|n| n.is_lowercase());
+                if p.is_lowercase() || p.is_numeric() || (p.is_uppercase() && next_is_lower) {
+                    split.push('-');
+                }
+            }
+        }
+        split.push(c);
+        prev = Some(c);
+    }
+    slugify(split)
+}
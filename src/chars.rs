@@ -0,0 +1,286 @@
+use deunicode::deunicode_char;
+
+// Emoji, their modifiers and joiners, which deunicode turns into words like `fire`
+pub(crate) fn is_emoji(c: char) -> bool {
+    matches!(c,
+        '\u{1F000}'..='\u{1FAFF}'
+        | '\u{2600}'..='\u{27BF}'
+        | '\u{2B00}'..='\u{2BFF}'
+        | '\u{E0020}'..='\u{E007F}'
+        | '\u{FE0F}'
+        | '\u{200D}'
+    )
+}
+
+// Symbols which deunicode transliterates inconsistently (`§` is `SS`), written as words
+const SYMBOL_WORDS: &[(char, &str)] = &[('§', "section"), ('¶', "para"), ('№', "no"), ('℅', "co")];
+
+// Word used instead of the transliteration of `c`, separated from its neighbours
+pub(crate) fn symbol_word(c: char) -> Option<&'static str> {
+    SYMBOL_WORDS
+        .iter()
+        .find(|&&(symbol, _)| symbol == c)
+        .map(|&(_, word)| word)
+}
+
+// Transliterations of the symbols of measurements, see `SlugOptions::measurement_symbols`.
+// The spaces become word breaks, so `°` ends the word and `±` is a word of its own
+const MEASUREMENT_SYMBOLS: &[(char, &str)] = &[
+    ('±', " pm "),
+    ('∓', " mp "),
+    ('°', "deg "),
+    ('℃', "deg c "),
+    ('℉', "deg f "),
+    ('×', "x"),
+    ('µ', "u"),
+];
+
+pub(crate) fn measurement_symbol(c: char) -> Option<&'static str> {
+    MEASUREMENT_SYMBOLS
+        .iter()
+        .find(|&&(symbol, _)| symbol == c)
+        .map(|&(_, ascii)| ascii)
+}
+
+// Middle dots and bullets separating the items of a list, like `tea • coffee`
+pub(crate) fn is_list_separator(c: char) -> bool {
+    matches!(c, '·' | '•' | '‣' | '▪' | '◦' | '⁃' | '∙')
+}
+
+// Unicode dashes, hyphens and minus signs, which are word breaks like an ascii `-`.
+// They don't go through the transliteration, which makes `—` a `--` and `〜` a `~ `
+pub(crate) fn is_dash(c: char) -> bool {
+    matches!(c,
+        '\u{058A}' | '\u{05BE}' | '\u{1400}' | '\u{1806}'
+        | '\u{2010}'..='\u{2015}'
+        | '\u{207B}' | '\u{208B}' | '\u{2212}' | '\u{2796}'
+        | '\u{2E17}' | '\u{2E1A}' | '\u{2E3A}' | '\u{2E3B}' | '\u{2E40}' | '\u{2E5D}'
+        | '\u{301C}' | '\u{3030}' | '\u{30A0}'
+        | '\u{FE31}' | '\u{FE32}' | '\u{FE58}' | '\u{FE63}' | '\u{FF0D}'
+        | '\u{10EAD}'
+    )
+}
+
+// Marks which are combined with the previous character, like U+0301 COMBINING ACUTE ACCENT
+pub(crate) fn is_combining_mark(c: char) -> bool {
+    matches!(c,
+        '\u{0300}'..='\u{036F}'
+        // Hebrew points and accents, Arabic harakat and Syriac vowels
+        | '\u{0591}'..='\u{05BD}'
+        | '\u{05BF}'
+        | '\u{05C1}'..='\u{05C2}'
+        | '\u{05C4}'..='\u{05C5}'
+        | '\u{05C7}'
+        | '\u{0610}'..='\u{061A}'
+        | '\u{064B}'..='\u{065F}'
+        | '\u{0670}'
+        | '\u{0711}'
+        | '\u{0730}'..='\u{074A}'
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{1DC0}'..='\u{1DFF}'
+        | '\u{20D0}'..='\u{20FF}'
+        | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+// Invisible characters which only control line breaking, like the soft hyphen in text pasted
+// from a PDF. deunicode already drops them, but the ICU transforms turn the soft hyphen into
+// `-`, so they are removed before
+pub(crate) fn is_elided_format(c: char) -> bool {
+    matches!(c, '\u{AD}' | '\u{2060}' | '\u{FEFF}')
+}
+
+// Non ascii characters which are dropped from the slug without separating the word, like
+// bidi marks or Hebrew points
+pub(crate) fn vanishes(c: char) -> bool {
+    !c.is_ascii() && transliterate(c) == Some("")
+}
+
+// Latin letters which deunicode doesn't transliterate or only to punctuation, like `Ə` to `@`.
+// Glottal stops are written `h`, like the saltillo in Nahuatl, and the Egyptological ain `a`,
+// like in `ankh`. Tone letters and the sinological dot are dropped without separating the
+// word. Sorted, for the binary search
+const LATIN_FOLDS: &[(char, &str)] = &[
+    ('Ǝ', "E"),
+    ('Ə', "E"),
+    ('ǝ', "e"),
+    ('Ɂ', "H"),
+    ('ɂ', "h"),
+    ('Ʌ', "V"),
+    ('ə', "e"),
+    ('ɚ', "e"),
+    ('ʌ', "v"),
+    ('ʔ', "h"),
+    ('ʡ', "h"),
+    ('Ꜣ', "A"),
+    ('ꜣ', "a"),
+    ('Ꜥ', "A"),
+    ('ꜥ', "a"),
+    ('Ꜿ', "C"),
+    ('ꜿ', "c"),
+    ('Ꝁ', "K"),
+    ('ꝁ', "k"),
+    ('Ꝃ', "K"),
+    ('ꝃ', "k"),
+    ('Ꝅ', "K"),
+    ('ꝅ', "k"),
+    ('Ꝉ', "L"),
+    ('ꝉ', "l"),
+    ('Ꝋ', "O"),
+    ('ꝋ', "o"),
+    ('Ꝑ', "P"),
+    ('ꝑ', "p"),
+    ('Ꝓ', "P"),
+    ('ꝓ', "p"),
+    ('Ꝕ', "P"),
+    ('ꝕ', "p"),
+    ('Ꝗ', "Q"),
+    ('ꝗ', "q"),
+    ('Ꝙ', "Q"),
+    ('ꝙ', "q"),
+    ('Ꝝ', "R"),
+    ('ꝝ', "r"),
+    ('Ꝟ', "V"),
+    ('ꝟ', "v"),
+    ('Ꝥ', "Th"),
+    ('ꝥ', "th"),
+    ('Ꝧ', "Th"),
+    ('ꝧ', "th"),
+    ('Ꝫ', "Et"),
+    ('ꝫ', "et"),
+    ('Ꝭ', "S"),
+    ('ꝭ', "s"),
+    ('Ꝯ', "Con"),
+    ('ꝯ', "con"),
+    ('ꝰ', "us"),
+    ('ꝱ', "d"),
+    ('ꝲ', "l"),
+    ('ꝳ', "m"),
+    ('ꝴ', "n"),
+    ('ꝵ', "r"),
+    ('ꝶ', "r"),
+    ('ꝷ', "t"),
+    ('ꝸ', "um"),
+    ('ꞈ', ""),
+    ('Ꞌ', "H"),
+    ('ꞌ', "h"),
+    ('ꞏ', ""),
+    ('\u{A7CB}', "U"),
+    ('\u{A7CC}', "S"),
+    ('\u{A7CD}', "s"),
+    ('\u{A7CE}', "A"),
+    ('\u{A7CF}', "a"),
+    ('\u{A7D2}', "Thth"),
+    ('\u{A7D4}', "Ww"),
+    ('\u{A7DA}', "L"),
+    ('\u{A7DB}', "l"),
+    ('\u{A7DC}', "L"),
+    ('\u{A7F1}', "S"),
+    ('ꟿ', "M"),
+];
+
+// Letters of right-to-left scripts which deunicode writes as punctuation, like `ע` as
+// `` ` ``, which would split the word. Glottal stops are dropped from the romanization.
+const RTL_FOLDS: &[(char, &str)] = &[
+    ('\u{05D0}', ""),
+    // The shin and sin dots, which deunicode writes as another `h`
+    ('\u{05C1}', ""),
+    ('\u{05C2}', ""),
+    ('\u{05E2}', ""),
+    ('\u{FB20}', ""),
+    ('\u{FB21}', ""),
+    ('\u{FB30}', ""),
+    ('\u{0621}', ""),
+    ('\u{0623}', ""),
+    ('\u{0625}', ""),
+    ('\u{0629}', "h"),
+    ('\u{0639}', ""),
+    ('\u{0649}', "a"),
+    ('\u{0671}', ""),
+    ('\u{0672}', ""),
+    ('\u{0673}', ""),
+    ('\u{0675}', ""),
+    ('\u{06C3}', "h"),
+    ('\u{FE80}', ""),
+    ('\u{FE83}', ""),
+    ('\u{FE84}', ""),
+    ('\u{FE87}', ""),
+    ('\u{FE88}', ""),
+    ('\u{FEC9}', ""),
+    ('\u{FECA}', ""),
+    ('\u{FECB}', ""),
+    ('\u{FECC}', ""),
+    ('\u{0710}', ""),
+    ('\u{0725}', ""),
+];
+
+// The ascii letter of `c` when it decomposes into one and combining marks, like `ñ` into `n`
+// and U+0303 COMBINING TILDE, see `LatinStrategy::StripMarks`
+pub(crate) fn strip_marks(c: char) -> Option<u8> {
+    let mut base = None;
+    let mut ascii = true;
+    unicode_normalization::char::decompose_canonical(c, |part| {
+        if unicode_normalization::char::is_combining_mark(part) {
+            return;
+        }
+        match base {
+            None if part.is_ascii_alphabetic() => base = Some(part as u8),
+            _ => ascii = false,
+        }
+    });
+    base.filter(|_| ascii)
+}
+
+// Ascii transliteration of a non ascii `c`, deunicode with the folds and without
+// separating words at combining marks
+pub(crate) fn transliterate(c: char) -> Option<&'static str> {
+    let fold = match LATIN_FOLDS.binary_search_by_key(&c, |&(letter, _)| letter) {
+        Ok(i) => Some(LATIN_FOLDS[i].1),
+        Err(_) => RTL_FOLDS
+            .iter()
+            .find(|&&(letter, _)| letter == c)
+            .map(|&(_, fold)| fold),
+    };
+    match fold {
+        Some(fold) => Some(fold),
+        None => match deunicode_char(c) {
+            None if is_combining_mark(c) => Some(""),
+            // Marks like the Hebrew dagesh, which deunicode writes as `'`
+            Some(ascii)
+                if is_combining_mark(c) && !ascii.bytes().any(|b| b.is_ascii_alphanumeric()) =>
+            {
+                Some("")
+            }
+            ascii => ascii,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fold(s: &str) -> String {
+        s.chars().map(|c| transliterate(c).unwrap_or("-")).collect()
+    }
+
+    #[test]
+    fn latin_folds_are_sorted() {
+        assert!(LATIN_FOLDS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn latin_letters_are_letters() {
+        // Letters deunicode already transliterates, they are not in the table
+        assert_eq!(fold("ǵḩẞ"), "ghSs");
+        assert_eq!(fold("ƏəǝƎ"), "EeeE");
+        assert_eq!(fold("ɁɂꞋꞌʔʡ"), "HhHhhh");
+        assert_eq!(fold("ꜥnḫ"), "anh");
+        assert_eq!(fold("Ʌʌꝯꝫꝥꝰꝸ"), "Vvconetthusum");
+        assert_eq!(fold("a\u{A788}b\u{A78F}c"), "abc");
+        assert_eq!(
+            fold("\u{A7CB}\u{A7CC}\u{A7CE}\u{A7D2}\u{A7D4}\u{A7DC}\u{A7F1}"),
+            "USAThthWwLS"
+        );
+    }
+}
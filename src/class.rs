@@ -0,0 +1,112 @@
+use crate::chars::{is_combining_mark, symbol_word, transliterate};
+use crate::slugify;
+
+/// What `slugify` does with a character, see [`slug_char_class`](fn.slug_char_class.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SlugCharClass {
+    /// Kept as it is, `a-z` and `0-9`
+    Kept,
+    /// Kept lowercased, `A-Z`
+    Lowercased,
+    /// Becomes a `-`, which is collapsed with the neighbouring separators
+    Separator,
+    /// Replaced by its ascii transliteration, like `é` by `e`
+    Transliterated,
+    /// Removed without separating words, like U+00AD SOFT HYPHEN
+    Dropped,
+}
+
+/// Classify `c` the same way `slugify` handles it
+///
+/// ```rust
+/// use slugmin::{slug_char_class, SlugCharClass};
+///
+/// assert_eq!(slug_char_class('a'), SlugCharClass::Kept);
+/// assert_eq!(slug_char_class('7'), SlugCharClass::Kept);
+/// assert_eq!(slug_char_class('Q'), SlugCharClass::Lowercased);
+/// assert_eq!(slug_char_class(' '), SlugCharClass::Separator);
+/// assert_eq!(slug_char_class('!'), SlugCharClass::Separator);
+/// assert_eq!(slug_char_class('—'), SlugCharClass::Separator);
+/// assert_eq!(slug_char_class('\u{E000}'), SlugCharClass::Separator);
+/// assert_eq!(slug_char_class('é'), SlugCharClass::Transliterated);
+/// assert_eq!(slug_char_class('東'), SlugCharClass::Transliterated);
+/// assert_eq!(slug_char_class('§'), SlugCharClass::Transliterated);
+/// assert_eq!(slug_char_class('\u{AD}'), SlugCharClass::Dropped);
+/// ```
+pub fn slug_char_class(c: char) -> SlugCharClass {
+    match c {
+        'a'..='z' | '0'..='9' => SlugCharClass::Kept,
+        'A'..='Z' => SlugCharClass::Lowercased,
+        _ if c.is_ascii() => SlugCharClass::Separator,
+        _ if symbol_word(c).is_some() => SlugCharClass::Transliterated,
+        _ => match transliterate(c) {
+            Some("") => SlugCharClass::Dropped,
+            Some(ascii) if ascii.bytes().any(|b| b.is_ascii_alphanumeric()) => {
+                SlugCharClass::Transliterated
+            }
+            // Only punctuation or no transliteration at all
+            _ => SlugCharClass::Separator,
+        },
+    }
+}
+
+/// Split `s` into graphemes, each with the part of the slug it contributes
+///
+/// The contribution is the slug of the grapheme alone, `None` for separators and dropped
+/// characters. Graphemes are approximated: a character is grouped with the combining marks,
+/// variation selectors and emoji modifiers following it, and with the character after a zero
+/// width joiner.
+///
+/// ```rust
+/// use slugmin::slug_trace;
+///
+/// let some = |s: &str| Some(s.to_string());
+/// assert_eq!(
+///     slug_trace("Ce\u{301}!"),
+///     [
+///         ("C".to_string(), some("c")),
+///         ("e\u{301}".to_string(), some("e")),
+///         ("!".to_string(), None),
+///     ]
+/// );
+/// let contributions: Vec<_> = slug_trace("é 🎉").into_iter().map(|(_, slug)| slug).collect();
+/// assert_eq!(contributions, [some("e"), None, some("tada")]);
+/// assert_eq!(slug_trace("👍🏽\u{AD}")[0].0, "👍🏽");
+/// assert_eq!(slug_trace("👍🏽\u{AD}")[1], ("\u{AD}".to_string(), None));
+/// assert!(slug_trace("").is_empty());
+/// ```
+pub fn slug_trace<S: AsRef<str>>(s: S) -> Vec<(String, Option<String>)> {
+    _slug_trace(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _slug_trace(s: &str) -> Vec<(String, Option<String>)> {
+    let mut trace = Vec::new();
+    let mut start = 0;
+    let mut chars = s.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        let mut joined = c == '\u{200D}';
+        while let Some(&(_, next)) = chars.peek() {
+            if !(joined || extends_grapheme(next)) {
+                break;
+            }
+            joined = next == '\u{200D}';
+            chars.next();
+        }
+        let end = chars.peek().map_or(s.len(), |&(i, _)| i);
+        let grapheme = &s[start..end];
+        let slug = slugify(grapheme);
+        trace.push((
+            grapheme.to_string(),
+            Some(slug).filter(|slug| !slug.is_empty()),
+        ));
+        start = end;
+    }
+    trace
+}
+
+// Whether `c` belongs to the grapheme of the character before it
+fn extends_grapheme(c: char) -> bool {
+    is_combining_mark(c)
+        || matches!(c, '\u{200D}' | '\u{FE00}'..='\u{FE0F}' | '\u{1F3FB}'..='\u{1F3FF}')
+}
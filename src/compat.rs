@@ -0,0 +1,292 @@
+use crate::chars::transliterate;
+use crate::percent_encode;
+use unicode_normalization::UnicodeNormalization;
+
+/// Convert a string to a slug exactly like Django's `django.utils.text.slugify`
+///
+/// Without `allow_unicode` the string is NFKD normalized and everything which isn't ascii
+/// is dropped (so `ß` is removed, not transliterated), otherwise it is NFKC normalized.
+/// Then it's lowercased, everything except letters, numbers, `_`, `-` and whitespace is
+/// removed, runs of `-` and whitespace become a single `-` and leading and trailing `-`
+/// and `_` are stripped. Unlike `slugify`, underscores are kept.
+///
+/// ```rust
+/// use slugmin::slugify_django;
+///
+/// assert_eq!(slugify_django("Hello, World!", false), "hello-world");
+/// assert_eq!(slugify_django("spam & eggs", false), "spam-eggs");
+/// assert_eq!(slugify_django(" multiple---dash and  space ", false), "multiple-dash-and-space");
+/// assert_eq!(slugify_django("\t whitespace-in-value \n", false), "whitespace-in-value");
+/// assert_eq!(slugify_django("underscore_in-value", false), "underscore_in-value");
+/// assert_eq!(slugify_django("__strip__underscore-value___", false), "strip__underscore-value");
+/// assert_eq!(slugify_django("--strip-dash-value---", false), "strip-dash-value");
+/// assert_eq!(slugify_django("_ -strip-mixed-value _-", false), "strip-mixed-value");
+/// assert_eq!(slugify_django("trailing -_", false), "trailing");
+/// assert_eq!(slugify_django("Straße Æsir", false), "strae-sir");
+/// assert_eq!(slugify_django("spam & ıçüş", true), "spam-ıçüş");
+/// assert_eq!(slugify_django("    foo ıç bar", true), "foo-ıç-bar");
+/// assert_eq!(slugify_django("你好", true), "你好");
+/// assert_eq!(slugify_django("İstanbul", true), "istanbul");
+/// ```
+pub fn slugify_django<S: AsRef<str>>(s: S, allow_unicode: bool) -> String {
+    _slugify_django(s.as_ref(), allow_unicode)
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_django(s: &str, allow_unicode: bool) -> String {
+    let normalized: String = if allow_unicode {
+        s.nfkc().collect()
+    } else {
+        s.nfkd().filter(char::is_ascii).collect()
+    };
+    let mut slug = String::with_capacity(normalized.len());
+    let mut pending_dash = false;
+    for c in normalized.to_lowercase().chars() {
+        if c == '-' || c.is_whitespace() {
+            pending_dash = true;
+        } else if c.is_alphanumeric() || c == '_' {
+            if pending_dash {
+                slug.push('-');
+                pending_dash = false;
+            }
+            slug.push(c);
+        }
+    }
+    let trimmed = slug.trim_matches(['-', '_']);
+    if trimmed.len() != slug.len() {
+        slug = trimmed.to_string();
+    }
+    slug
+}
+
+/// Convert a string to a slug exactly like Rails' `String#parameterize`
+///
+/// The string is transliterated to ascii, every run of characters other than ascii letters,
+/// digits, `-` and `_` becomes `separator`, repeated separators are collapsed and a leading
+/// and trailing separator is removed. The result is lowercased unless `preserve_case` is set.
+/// Like in Rails, `-` and `_` from the input are kept even with a different separator.
+///
+/// ```rust
+/// use slugmin::parameterize;
+///
+/// assert_eq!(parameterize("Donald E. Knuth", '-', false), "donald-e-knuth");
+/// assert_eq!(parameterize("Donald E. Knuth", '_', false), "donald_e_knuth");
+/// assert_eq!(parameterize("Donald E. Knuth", '-', true), "Donald-E-Knuth");
+/// assert_eq!(parameterize("^très|Jolie-- ", '-', false), "tres-jolie");
+/// assert_eq!(parameterize("^très|Jolie__ ", '_', false), "tres_jolie");
+/// assert_eq!(parameterize("^très_Jolie-- ", '_', false), "tres_jolie--");
+/// assert_eq!(parameterize("Ruby_on-Rails", '-', false), "ruby_on-rails");
+/// assert_eq!(parameterize("Məmmədov", '-', false), "memmedov");
+/// ```
+pub fn parameterize<S: AsRef<str>>(s: S, separator: char, preserve_case: bool) -> String {
+    _parameterize(s.as_ref(), separator, preserve_case)
+}
+
+// avoid unnecessary monomorphizations
+fn _parameterize(s: &str, separator: char, preserve_case: bool) -> String {
+    let mut slug = String::with_capacity(s.len());
+    let mut in_replaced_run = false;
+    let mut push_char = |c: char| {
+        if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+            in_replaced_run = false;
+            // Repeated separators are collapsed, also those from the input
+            if c != separator || !slug.ends_with(separator) {
+                slug.push(if preserve_case {
+                    c
+                } else {
+                    c.to_ascii_lowercase()
+                });
+            }
+        } else if !in_replaced_run {
+            in_replaced_run = true;
+            if !slug.ends_with(separator) {
+                slug.push(separator);
+            }
+        }
+    };
+    for c in s.chars() {
+        if c.is_ascii() {
+            push_char(c);
+        } else {
+            // Rails uses `?` for characters without transliteration
+            for cx in transliterate(c).unwrap_or("?").chars() {
+                push_char(cx);
+            }
+        }
+    }
+    if slug.starts_with(separator) {
+        slug.remove(0);
+    }
+    if slug.ends_with(separator) {
+        slug.pop();
+    }
+    slug
+}
+
+/// Encode a string as the value of a query parameter, like `application/x-www-form-urlencoded`
+///
+/// Nothing is lowercased or transliterated: ascii letters, digits, `-`, `_`, `.` and `~` are
+/// kept, a space becomes `+` and everything else, including `+` itself, is percent encoded as
+/// UTF-8 with uppercase hexadecimal digits. A decoder treating `+` as `%20` gets `s` back.
+///
+/// ```rust
+/// use slugmin::slugify_query_value;
+///
+/// assert_eq!(slugify_query_value("Crème brûlée"), "Cr%C3%A8me+br%C3%BBl%C3%A9e");
+/// assert_eq!(slugify_query_value("a+b=c&d"), "a%2Bb%3Dc%26d");
+/// assert_eq!(slugify_query_value("v1.2_beta~3-rc"), "v1.2_beta~3-rc");
+/// assert_eq!(slugify_query_value("tab\there"), "tab%09here");
+/// ```
+pub fn slugify_query_value<S: AsRef<str>>(s: S) -> String {
+    _slugify_query_value(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_query_value(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' | '~' => encoded.push(c),
+            ' ' => encoded.push('+'),
+            _ => percent_encode(c, |b| encoded.push(b as char)),
+        }
+    }
+    encoded
+}
+
+/// Convert a post title to a slug like WordPress' `sanitize_title` when saving a post
+///
+/// Accents of latin letters are removed, HTML tags are stripped, `%` is removed unless it
+/// starts a percent encoded octet, and the title is lowercased. Other non ascii characters
+/// are percent encoded (the encoded title is cut at 200 bytes), except for non breaking
+/// spaces and dashes, which become `-`, `×`, which becomes `x`, and quotes, bullets and
+/// similar symbols, which are removed. HTML entities are removed entirely, as WordPress does,
+/// after `&nbsp;`, `&ndash;` and `&mdash;` became `-`. At last `.` and `/` become `-`,
+/// everything except `%`, ascii letters, digits, `_`, `-` and spaces is removed, spaces
+/// become `-`, runs of `-` are collapsed and leading and trailing `-` are removed.
+///
+/// ```rust
+/// use slugmin::sanitize_title;
+///
+/// assert_eq!(sanitize_title("Hello &amp; Goodbye"), "hello-goodbye");
+/// assert_eq!(sanitize_title("Crème Brûlée"), "creme-brulee");
+/// assert_eq!(sanitize_title("Ǝrdǝm"), "erdem");
+/// assert_eq!(sanitize_title("<b>Bold</b> move"), "bold-move");
+/// assert_eq!(sanitize_title("Don’t stop – believing"), "dont-stop-believing");
+/// assert_eq!(sanitize_title("50% off, 2×1"), "50-off-2x1");
+/// assert_eq!(sanitize_title("Encoded%C3%A9 stays"), "encoded%c3%a9-stays");
+/// assert_eq!(sanitize_title("Tokyo 東京"), "tokyo-%e6%9d%b1%e4%ba%ac");
+/// assert_eq!(sanitize_title("v1.2/notes_draft"), "v1-2-notes_draft");
+/// ```
+pub fn sanitize_title<S: AsRef<str>>(s: S) -> String {
+    _sanitize_title(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _sanitize_title(s: &str) -> String {
+    // WordPress limits the length of `utf8_uri_encode` output
+    const ENCODED_LIMIT: usize = 200;
+
+    let mut encoded = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            // strip_tags()
+            '<' => {
+                for tag_char in chars.by_ref() {
+                    if tag_char == '>' {
+                        break;
+                    }
+                }
+                continue;
+            }
+            // Only percent signs starting an octet are kept
+            '%' => {
+                let mut octet = chars.clone();
+                if !(octet.next().is_some_and(|h| h.is_ascii_hexdigit())
+                    && octet.next().is_some_and(|h| h.is_ascii_hexdigit()))
+                {
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        let c = match c {
+            _ if is_latin_letter(c) => {
+                // remove_accents()
+                for ac in transliterate(c).unwrap_or("").chars() {
+                    if encoded.len() >= ENCODED_LIMIT {
+                        break;
+                    }
+                    encoded.push(ac.to_ascii_lowercase());
+                }
+                continue;
+            }
+            '\u{A0}' | '\u{2013}' | '\u{2014}' => '-',
+            '\u{D7}' => 'x',
+            _ if is_wordpress_stripped(c) => continue,
+            _ => c,
+        };
+        if c.is_ascii() {
+            if encoded.len() >= ENCODED_LIMIT {
+                break;
+            }
+            encoded.push(c.to_ascii_lowercase());
+        } else {
+            for lc in c.to_lowercase() {
+                if encoded.len() + lc.len_utf8() * 3 > ENCODED_LIMIT {
+                    break;
+                }
+                percent_encode(lc, |b| encoded.push(b.to_ascii_lowercase() as char));
+            }
+        }
+    }
+
+    for entity in [
+        "&nbsp;", "&#160;", "&ndash;", "&#8211;", "&mdash;", "&#8212;",
+    ] {
+        if encoded.contains(entity) {
+            encoded = encoded.replace(entity, "-");
+        }
+    }
+
+    let mut slug = String::with_capacity(encoded.len());
+    let mut rest = encoded.as_str();
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+        match c {
+            // Entities, `&.+?;`, are killed
+            '&' if rest.len() > 1 && rest[1..].contains(';') => {
+                let end = 1 + rest[1..].find(';').unwrap_or(0);
+                rest = &rest[end + 1..];
+            }
+            '.' | '/' | '-' | ' ' | '\t' | '\n' | '\r' | '\x0C'
+                if !slug.is_empty() && !slug.ends_with('-') =>
+            {
+                slug.push('-')
+            }
+            'a'..='z' | '0'..='9' | '%' | '_' => slug.push(c),
+            _ => {}
+        }
+    }
+    if slug.ends_with('-') {
+        slug.pop();
+    }
+    slug
+}
+
+fn is_latin_letter(c: char) -> bool {
+    c.is_alphabetic()
+        && matches!(c, '\u{AA}' | '\u{BA}' | '\u{C0}'..='\u{24F}' | '\u{1E00}'..='\u{1EFF}')
+}
+
+// Characters which WordPress strips entirely when saving a title
+fn is_wordpress_stripped(c: char) -> bool {
+    matches!(c,
+        '\u{AD}' | '¡' | '¿' | '«' | '»' | '‹' | '›'
+        | '‘' | '’' | '“' | '”' | '‚' | '‛' | '„' | '‟'
+        | '•' | '©' | '®' | '°' | '…' | '™'
+        | '´' | 'ˊ' | '\u{301}' | '\u{341}' | '\u{300}' | '\u{304}' | '\u{30C}'
+        | '\u{200B}'..='\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{FEFF}'
+    )
+}
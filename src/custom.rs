@@ -0,0 +1,183 @@
+use crate::chars::transliterate;
+use crate::options::_slugify_with_fallback;
+use crate::{percent_encode, slugify, SlugOptions};
+
+/// Convert any unicode string to a slug keeping only the characters accepted by `keep`
+///
+/// The input is transliterated first like by `slugify`, so `keep` only sees ascii characters (in
+/// their original case). Accepted characters are pushed, lowercased unless `leave_size` is true,
+/// every run of rejected characters becomes a single `separator`, which is never leading or
+/// trailing.
+///
+/// ```rust
+/// use slugmin::slugify_with_filter;
+///
+/// let consonants = |c: char| c.is_ascii_alphabetic() && !"aeiouAEIOU".contains(c);
+/// assert_eq!(slugify_with_filter("Hello World", consonants, '-', false), "h-ll-w-rld");
+/// assert_eq!(slugify_with_filter("Hello World", consonants, '-', true), "H-ll-W-rld");
+/// assert_eq!(slugify_with_filter("Æsir café", consonants, '_', false), "s_r_c_f");
+/// assert_eq!(slugify_with_filter("Məmmədov", |c| c.is_ascii_alphanumeric(), '-', false), "memmedov");
+/// assert_eq!(slugify_with_filter("a+b=c", |c| c.is_ascii_alphanumeric() || c == '+', '-', false), "a+b-c");
+/// ```
+pub fn slugify_with_filter<S: AsRef<str>, F: Fn(char) -> bool>(
+    s: S,
+    keep: F,
+    separator: char,
+    leave_size: bool,
+) -> String {
+    _slugify_with_filter(s.as_ref(), &keep, separator, leave_size)
+}
+
+// avoid unnecessary monomorphizations
+pub(crate) fn _slugify_with_filter(
+    s: &str,
+    keep: &dyn Fn(char) -> bool,
+    separator: char,
+    leave_size: bool,
+) -> String {
+    let mut slug = String::with_capacity(s.len());
+    // Starts with true to avoid leading separator
+    let mut prev_is_separator = true;
+    let mut push_char = |c: char| {
+        if keep(c) {
+            slug.push(if leave_size {
+                c
+            } else {
+                c.to_ascii_lowercase()
+            });
+            prev_is_separator = false;
+        } else if !prev_is_separator {
+            slug.push(separator);
+            prev_is_separator = true;
+        }
+    };
+    for c in s.chars() {
+        if c.is_ascii() {
+            push_char(c);
+        } else {
+            for cx in transliterate(c).unwrap_or("-").chars() {
+                push_char(cx);
+            }
+        }
+    }
+    if slug.ends_with(separator) {
+        slug.pop();
+    }
+    slug
+}
+
+/// Convert any unicode string to an ascii "slug" like `slugify`, asking `fallback` for characters
+/// which can't be transliterated
+///
+/// `fallback` is only called when deunicode has no transliteration. Its `Some` result goes
+/// through the same rules as the rest of the input, `None` produces `-`. Many symbols like
+/// `№` (`No`) or `§` (`SS`) are already transliterated, so it is not called for them.
+///
+/// ```rust
+/// use slugmin::slugify_with_fallback;
+///
+/// let fallback = |c: char| match c {
+///     '\u{E000}' => Some("our logo".to_string()),
+///     '№' => Some("number".to_string()),
+///     _ => None,
+/// };
+/// assert_eq!(slugify_with_fallback("Made by \u{E000}!", fallback), "made-by-our-logo");
+/// assert_eq!(slugify_with_fallback("a\u{E001}b", fallback), "a-b");
+/// assert_eq!(slugify_with_fallback("№ 5", fallback), "no-5");
+/// ```
+pub fn slugify_with_fallback<S: AsRef<str>, F: Fn(char) -> Option<String>>(
+    s: S,
+    fallback: F,
+) -> String {
+    _slugify_with_fallback(s.as_ref(), &SlugOptions::new(), Some(&fallback))
+}
+
+/// Convert any unicode string to a slug like `slugify`, but write the `protected` terms, like
+/// brand names, exactly as they are listed
+///
+/// Terms are matched ascii case-insensitively and only as whole words, a term starting or
+/// ending with a letter or digit doesn't match inside a longer word. Where terms overlap the
+/// longest one starting first wins, adjacent terms are separated by `-`. Characters of a term
+/// which are not allowed in an URL path segment unescaped are percent encoded, so `C#` is
+/// written as `C%23`.
+///
+/// As the terms keep their case and may contain `.`, `_`, `~` or `%`, the result is usually
+/// not a slug, and it is not idempotent: the escaped term no longer matches, so slugifying
+/// `learn-C%23-today` again, even with the same terms, gives `learn-c-23-today`.
+///
+/// ```rust
+/// use slugmin::slugify_with_protected;
+///
+/// assert_eq!(slugify_with_protected("Learn C# Today", &["C#"]), "learn-C%23-today");
+/// assert_eq!(slugify_with_protected("learn c# today", &["C#"]), "learn-C%23-today");
+/// assert_eq!(slugify_with_protected("Tuning postgresql 16", &["PostgreSQL"]), "tuning-PostgreSQL-16");
+/// assert_eq!(slugify_with_protected("PostgreSQLite", &["PostgreSQL"]), "postgresqlite");
+/// assert_eq!(slugify_with_protected("Node.js and Node", &["Node", "Node.js"]), "Node.js-and-Node");
+/// assert_eq!(slugify_with_protected("C#C++", &["C#", "C++"]), "C%23-C%2B%2B");
+/// assert_eq!(slugify_with_protected("learn-C%23-today", &["C#"]), "learn-c-23-today");
+/// ```
+pub fn slugify_with_protected<S: AsRef<str>>(s: S, protected: &[&str]) -> String {
+    _slugify_with_protected(s.as_ref(), protected)
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_with_protected(s: &str, protected: &[&str]) -> String {
+    let mut slug = String::with_capacity(s.len());
+    let mut unprotected_start = 0;
+    let mut i = 0;
+    while let Some(c) = s[i..].chars().next() {
+        match protected_term_at(s, i, protected) {
+            Some(term) => {
+                push_part(&mut slug, &slugify(&s[unprotected_start..i]));
+                let mut escaped = String::with_capacity(term.len());
+                for tc in term.chars() {
+                    if tc.is_ascii_alphanumeric() || "-._~".contains(tc) {
+                        escaped.push(tc);
+                    } else {
+                        percent_encode(tc, |b| escaped.push(b as char));
+                    }
+                }
+                push_part(&mut slug, &escaped);
+                i += term.len();
+                unprotected_start = i;
+            }
+            None => i += c.len_utf8(),
+        }
+    }
+    push_part(&mut slug, &slugify(&s[unprotected_start..]));
+    slug
+}
+
+// The longest of the `protected` terms which matches `s` at byte `i` as a whole word
+fn protected_term_at<'a>(s: &str, i: usize, protected: &[&'a str]) -> Option<&'a str> {
+    let is_word_char = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    protected
+        .iter()
+        .copied()
+        .filter(|term| {
+            let end = i + term.len();
+            let Some(candidate) = s.get(i..end) else {
+                return false;
+            };
+            // Only a term edge which is a letter or digit can continue a word
+            let joins_previous =
+                is_word_char(term.chars().next()) && is_word_char(s[..i].chars().next_back());
+            let joins_next =
+                is_word_char(term.chars().next_back()) && is_word_char(s[end..].chars().next());
+            !term.is_empty()
+                && candidate.eq_ignore_ascii_case(term)
+                && !joins_previous
+                && !joins_next
+        })
+        .max_by_key(|term| term.len())
+}
+
+fn push_part(slug: &mut String, part: &str) {
+    if part.is_empty() {
+        return;
+    }
+    if !slug.is_empty() {
+        slug.push('-');
+    }
+    slug.push_str(part);
+}
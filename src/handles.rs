@@ -0,0 +1,202 @@
+use crate::chars::{is_emoji, transliterate};
+use crate::custom::_slugify_with_filter;
+use crate::slugify;
+
+/// Convert a display name to a Mastodon (Fediverse) username
+///
+/// The result only contains ascii letters, digits and `_`, runs of other characters become a
+/// single `_` and it's cut to 30 characters, the limit of Mastodon. Case is preserved, as
+/// usernames are case-insensitive but displayed as written. An empty string is returned when
+/// the name has no letters or digits.
+///
+/// ```rust
+/// use slugmin::slugify_fedi_username;
+///
+/// assert_eq!(slugify_fedi_username("Jean-Luc Picard"), "Jean_Luc_Picard");
+/// assert_eq!(slugify_fedi_username("  Zoë_the--Coder! "), "Zoe_the_Coder");
+/// assert_eq!(
+///     slugify_fedi_username("The Quick Brown Fox Jumps Over The Lazy Dog"),
+///     "The_Quick_Brown_Fox_Jumps_Over"
+/// );
+/// assert_eq!(slugify_fedi_username("The Quick Brown Fox Jumps Over_"), "The_Quick_Brown_Fox_Jumps_Over");
+/// assert_eq!(slugify_fedi_username("!!!"), "");
+/// ```
+pub fn slugify_fedi_username<S: AsRef<str>>(s: S) -> String {
+    let mut username = _slugify_with_filter(s.as_ref(), &|c| c.is_ascii_alphanumeric(), '_', true);
+    truncate(&mut username, 30, &['_']);
+    username
+}
+
+/// Convert a name to an IRC channel name
+///
+/// The name is slugified, so it's lowercase and has no spaces, commas, colons or control
+/// characters, and prefixed by `#`. A leading `#` or `&` of the name is used as the prefix
+/// instead. The channel name is cut to 50 characters, the common limit, prefix included.
+/// A name without letters or digits gives just the prefix.
+///
+/// ```rust
+/// use slugmin::slugify_irc_channel;
+///
+/// assert_eq!(slugify_irc_channel("My Cool Room!"), "#my-cool-room");
+/// assert_eq!(slugify_irc_channel("#Rust, Beginners"), "#rust-beginners");
+/// assert_eq!(slugify_irc_channel("&local: ops"), "&local-ops");
+/// assert_eq!(slugify_irc_channel("!!!"), "#");
+///
+/// let channel = slugify_irc_channel("a very long channel name which goes on and on and on forever");
+/// assert_eq!(channel, "#a-very-long-channel-name-which-goes-on-and-on-and");
+/// assert!(channel.len() <= 50);
+/// ```
+pub fn slugify_irc_channel<S: AsRef<str>>(s: S) -> String {
+    _slugify_irc_channel(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_irc_channel(s: &str) -> String {
+    let s = s.trim_start();
+    let (prefix, name) = match s.chars().next() {
+        Some(prefix @ ('#' | '&')) => (prefix, &s[1..]),
+        _ => ('#', s),
+    };
+    let mut channel = String::with_capacity(s.len() + 1);
+    channel.push(prefix);
+    channel.push_str(&slugify(name));
+    truncate(&mut channel, 50, &['-']);
+    channel
+}
+
+/// Convert a name to a Slack channel name
+///
+/// The result is lowercase, only contains ascii letters, digits, `-` and `_` and is cut to 80
+/// characters. Runs of other characters, like spaces and periods, become a single `-` and
+/// emoji are removed instead of being spelled out.
+///
+/// ```rust
+/// use slugmin::slugify_slack_channel;
+///
+/// assert_eq!(slugify_slack_channel("Project: Phoenix 🔥"), "project-phoenix");
+/// assert_eq!(slugify_slack_channel("team_backend v2.0"), "team_backend-v2-0");
+///
+/// let channel = slugify_slack_channel("abcdefghij ".repeat(8));
+/// assert_eq!(channel.len(), 80);
+/// assert!(channel.ends_with("-abc"));
+/// ```
+pub fn slugify_slack_channel<S: AsRef<str>>(s: S) -> String {
+    _slugify_slack_channel(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_slack_channel(s: &str) -> String {
+    let without_emoji: String = s
+        .chars()
+        .map(|c| if is_emoji(c) { ' ' } else { c })
+        .collect();
+    let keep = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut channel = _slugify_with_filter(&without_emoji, &keep, '-', false);
+    truncate(&mut channel, 80, &['-']);
+    channel
+}
+
+/// Convert a name to a Telegram username
+///
+/// Telegram usernames have 5 to 32 ascii letters, digits and `_`, start with a letter and
+/// neither end with `_` nor contain `__`. Runs of other characters become a single `_` and
+/// case is preserved. When the name doesn't start with a letter, `user_` is put in front,
+/// so the username is `user` (padded to `user0`) for names without letters or digits. Names
+/// shorter than 5 characters are padded with `0`, longer ones are cut to 32 characters.
+///
+/// ```rust
+/// use slugmin::slugify_telegram_username;
+///
+/// assert_eq!(slugify_telegram_username("Álvaro  Díaz!"), "Alvaro_Diaz");
+/// assert_eq!(slugify_telegram_username("Bob"), "Bob00");
+/// assert_eq!(slugify_telegram_username("2fast4you"), "user_2fast4you");
+/// assert_eq!(slugify_telegram_username("__init__"), "init0");
+/// assert_eq!(slugify_telegram_username("!!!"), "user0");
+/// assert_eq!(
+///     slugify_telegram_username("The quick brown fox jumps over the lazy dog"),
+///     "The_quick_brown_fox_jumps_over_t"
+/// );
+/// ```
+pub fn slugify_telegram_username<S: AsRef<str>>(s: S) -> String {
+    _slugify_telegram_username(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_telegram_username(s: &str) -> String {
+    let name = _slugify_with_filter(s, &|c| c.is_ascii_alphanumeric(), '_', true);
+    let mut username = if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name
+    } else {
+        format!("user_{}", name)
+    };
+    truncate(&mut username, 32, &['_']);
+    while username.len() < 5 {
+        username.push('0');
+    }
+    username
+}
+
+/// Convert a name to a platform handle, like a YouTube `@handle`
+///
+/// Handles have 3 to 30 lowercase ascii letters, digits, `.`, `_` and `-`. Every run of other
+/// characters becomes a single separator, which is the first `.`, `_` or `-` of the run, or `.`
+/// when it has none. Separators are never leading or trailing. Short handles are padded with
+/// `0`, long ones are cut to 30 characters.
+///
+/// ```rust
+/// use slugmin::slugify_handle;
+///
+/// assert_eq!(slugify_handle("Cool Channel 2024"), "cool.channel.2024");
+/// assert_eq!(slugify_handle("my_handle - Official!"), "my_handle-official");
+/// assert_eq!(slugify_handle("...Jo"), "jo0");
+/// assert_eq!(slugify_handle("Məmmədov"), "memmedov");
+/// assert_eq!(slugify_handle("The Most Amazing Cooking Channel Ever"), "the.most.amazing.cooking.chann");
+/// ```
+pub fn slugify_handle<S: AsRef<str>>(s: S) -> String {
+    _slugify_handle(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_handle(s: &str) -> String {
+    let mut handle = String::with_capacity(s.len());
+    // Separator of the current run, and whether it comes from the input
+    let mut pending: Option<(char, bool)> = None;
+    let mut push_char = |c: char| {
+        if c.is_ascii_alphanumeric() {
+            if let Some((separator, _)) = pending.take() {
+                if !handle.is_empty() {
+                    handle.push(separator);
+                }
+            }
+            handle.push(c.to_ascii_lowercase());
+        } else if matches!(c, '.' | '_' | '-') {
+            if !matches!(pending, Some((_, true))) {
+                pending = Some((c, true));
+            }
+        } else if pending.is_none() {
+            pending = Some(('.', false));
+        }
+    };
+    for c in s.chars() {
+        if c.is_ascii() {
+            push_char(c);
+        } else {
+            for cx in transliterate(c).unwrap_or("-").chars() {
+                push_char(cx);
+            }
+        }
+    }
+    truncate(&mut handle, 30, &['.', '_', '-']);
+    while handle.len() < 3 {
+        handle.push('0');
+    }
+    handle
+}
+
+// Cuts an ascii `s` to at most `max` bytes, then removes the trailing `trim` characters
+fn truncate(s: &mut String, max: usize, trim: &[char]) {
+    s.truncate(max);
+    while s.ends_with(trim) {
+        s.pop();
+    }
+}
@@ -0,0 +1,124 @@
+use crate::slugify;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+// 64 bit FNV-1a, the same on every run and platform unlike `RandomState`
+pub(crate) fn fnv1a(bytes: &[u8], basis: u64) -> u64 {
+    bytes.iter().fold(basis, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Slugify `s` to a cache key of exactly 32 bytes, the start of the slug followed by a hash
+///
+/// The key is made of at most the first 15 bytes of the slug, a `-` and hexadecimal digits of
+/// a hash of the whole slug filling the rest. The hash is stable across runs and platforms,
+/// but it's not cryptographic. As the slug is hashed, inputs with the same slug share a key.
+///
+/// ```rust
+/// use slugmin::slug_cache_key;
+///
+/// let first = slug_cache_key("The Complete History of the Roman Empire, Part One");
+/// let second = slug_cache_key("The Complete History of the Roman Empire, Part Two");
+/// assert_eq!(first.len(), 32);
+/// assert_eq!(second.len(), 32);
+/// assert_ne!(first, second);
+/// assert!(first.starts_with("the-complete-hi-"));
+/// assert_eq!(slug_cache_key("My Post").len(), 32);
+/// assert!(slug_cache_key("My Post").starts_with("my-post-"));
+/// assert_eq!(slug_cache_key("My Post"), slug_cache_key("my-post"));
+/// assert_eq!(slug_cache_key("!!!").len(), 32);
+/// ```
+pub fn slug_cache_key<S: AsRef<str>>(s: S) -> String {
+    _slug_cache_key(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _slug_cache_key(s: &str) -> String {
+    const KEY_LEN: usize = 32;
+    const PREFIX_LEN: usize = 15;
+
+    let slug = slugify(s);
+    let first = fnv1a(slug.as_bytes(), FNV_OFFSET_BASIS);
+    let second = fnv1a(slug.as_bytes(), first);
+    let digits = format!("{:016x}{:016x}", first, second);
+
+    let prefix = slug[..slug.len().min(PREFIX_LEN)].trim_end_matches('-');
+    let mut key = String::with_capacity(KEY_LEN);
+    if !prefix.is_empty() {
+        key.push_str(prefix);
+        key.push('-');
+    }
+    let missing = KEY_LEN - key.len();
+    key.push_str(&digits[..missing]);
+    key
+}
+
+/// Shard index in `0..shards` of the slug of `s`, for bucketing slugs in sharded storage
+///
+/// Like [`slug_cache_key`](fn.slug_cache_key.html) the slug is hashed, so inputs with the same
+/// slug share a shard, and the hash is stable across runs and platforms.
+///
+/// # Panics
+///
+/// When `shards` is 0.
+///
+/// ```rust
+/// use slugmin::slug_shard;
+///
+/// assert_eq!(slug_shard("My Post", 16), slug_shard("my-post", 16));
+/// assert_eq!(slug_shard("My Post!!!", 16), slug_shard("  my post", 16));
+/// assert!(slug_shard("Another post", 16) < 16);
+/// assert_eq!(slug_shard("My Post", 1), 0);
+/// // The same everywhere, so it can be stored
+/// assert_eq!(slug_shard("My Post", 1024), 620);
+/// ```
+pub fn slug_shard<S: AsRef<str>>(s: S, shards: u32) -> u32 {
+    assert!(shards > 0, "shards must be positive");
+    let hash = fnv1a(slugify(s).as_bytes(), FNV_OFFSET_BASIS);
+    // The remainder is smaller than `shards`
+    (hash % u64::from(shards)) as u32
+}
+
+/// Abbreviate the slug of `s` to the initials of its words and `hash_len` hexadecimal digits
+/// of a hash of the whole slug, for short but recognizable links
+///
+/// The hash is the same for every run and platform and keeps abbreviations of different
+/// titles with the same initials apart, up to 16 digits are used. A slug of a single word is
+/// cut to at most 8 bytes instead and has no hash.
+///
+/// ```rust
+/// use slugmin::slugify_abbrev;
+///
+/// let abbrev = slugify_abbrev("The Quick Brown Fox", 2);
+/// assert!(abbrev.starts_with("tqbf-"));
+/// assert_eq!(abbrev.len(), 7);
+/// assert_eq!(abbrev, slugify_abbrev("the-quick-brown-fox", 2));
+/// assert_ne!(abbrev, slugify_abbrev("The Quick Brown Frog", 2));
+/// assert_eq!(slugify_abbrev("Hello, World", 0), "hw");
+/// assert_eq!(slugify_abbrev("Supercalifragilistic", 4), "supercal");
+/// assert_eq!(slugify_abbrev("Ok!", 4), "ok");
+/// assert_eq!(slugify_abbrev("!!!", 4), "");
+/// ```
+pub fn slugify_abbrev<S: AsRef<str>>(s: S, hash_len: usize) -> String {
+    _slugify_abbrev(s.as_ref(), hash_len)
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_abbrev(s: &str, hash_len: usize) -> String {
+    const MAX_WORD_LEN: usize = 8;
+
+    let slug = slugify(s);
+    if !slug.contains('-') {
+        return slug[..slug.len().min(MAX_WORD_LEN)].to_string();
+    }
+    // Words of a slug are never empty
+    let mut abbrev: String = slug.split('-').map(|word| &word[..1]).collect();
+    if hash_len > 0 {
+        let digits = format!("{:016x}", fnv1a(slug.as_bytes(), FNV_OFFSET_BASIS));
+        abbrev.push('-');
+        abbrev.push_str(&digits[..hash_len.min(digits.len())]);
+    }
+    abbrev
+}
@@ -0,0 +1,32 @@
+use icu_experimental::transliterate::Transliterator;
+
+// Scripts with a CLDR transform to latin in the ICU4X data, Thai and some others are missing
+const SCRIPTS: &[&str] = &[
+    "arab", "armn", "beng", "cyrl", "deva", "ethi", "geor", "grek", "gujr", "guru", "hang", "hans",
+    "hant", "hebr", "hira", "kana", "knda", "mlym", "orya", "syrc", "taml", "telu", "thaa",
+];
+
+thread_local! {
+    // Transliterators are not `Sync` and expensive to create, so every thread creates them once
+    static TRANSLITERATORS: Vec<Transliterator> = SCRIPTS
+        .iter()
+        .map(|script| format!("und-Latn-t-und-{}", script))
+        // Latin-ASCII must be the last one
+        .chain(std::iter::once("und-t-und-latn-d0-ascii".to_string()))
+        .map(|id| {
+            let locale = id.parse().expect("valid transform id");
+            Transliterator::try_new(&locale).expect("transform available in compiled data")
+        })
+        .collect();
+}
+
+// Any-Latin; Latin-ASCII with the available transforms
+pub(crate) fn transliterate(s: &str) -> String {
+    TRANSLITERATORS.with(|transliterators| {
+        transliterators
+            .iter()
+            .fold(s.to_string(), |s, transliterator| {
+                transliterator.transliterate(s)
+            })
+    })
+}
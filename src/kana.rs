@@ -0,0 +1,170 @@
+// Hepburn romanization of hiragana and katakana, see `SlugOptions::kana_romaji`
+
+// Romaji of a single kana, katakana are looked up as the matching hiragana
+fn syllable(c: char) -> Option<&'static str> {
+    let c = match c {
+        '\u{30A1}'..='\u{30F6}' => char::from_u32(c as u32 - 0x60)?,
+        _ => c,
+    };
+    let romaji = match c {
+        'ぁ' | 'あ' => "a",
+        'ぃ' | 'い' | 'ゐ' => "i",
+        'ぅ' | 'う' => "u",
+        'ぇ' | 'え' | 'ゑ' => "e",
+        'ぉ' | 'お' | 'を' => "o",
+        'か' | 'ゕ' => "ka",
+        'が' => "ga",
+        'き' => "ki",
+        'ぎ' => "gi",
+        'く' => "ku",
+        'ぐ' => "gu",
+        'け' | 'ゖ' => "ke",
+        'げ' => "ge",
+        'こ' => "ko",
+        'ご' => "go",
+        'さ' => "sa",
+        'ざ' => "za",
+        'し' => "shi",
+        'じ' | 'ぢ' => "ji",
+        'す' => "su",
+        'ず' | 'づ' => "zu",
+        'せ' => "se",
+        'ぜ' => "ze",
+        'そ' => "so",
+        'ぞ' => "zo",
+        'た' => "ta",
+        'だ' => "da",
+        'ち' => "chi",
+        'つ' => "tsu",
+        'て' => "te",
+        'で' => "de",
+        'と' => "to",
+        'ど' => "do",
+        'な' => "na",
+        'に' => "ni",
+        'ぬ' => "nu",
+        'ね' => "ne",
+        'の' => "no",
+        'は' => "ha",
+        'ば' => "ba",
+        'ぱ' => "pa",
+        'ひ' => "hi",
+        'び' => "bi",
+        'ぴ' => "pi",
+        'ふ' => "fu",
+        'ぶ' => "bu",
+        'ぷ' => "pu",
+        'へ' => "he",
+        'べ' => "be",
+        'ぺ' => "pe",
+        'ほ' => "ho",
+        'ぼ' => "bo",
+        'ぽ' => "po",
+        'ま' => "ma",
+        'み' => "mi",
+        'む' => "mu",
+        'め' => "me",
+        'も' => "mo",
+        'ゃ' | 'や' => "ya",
+        'ゅ' | 'ゆ' => "yu",
+        'ょ' | 'よ' => "yo",
+        'ら' => "ra",
+        'り' => "ri",
+        'る' => "ru",
+        'れ' => "re",
+        'ろ' => "ro",
+        'ゎ' | 'わ' => "wa",
+        'ん' => "n",
+        'ゔ' => "vu",
+        _ => return None,
+    };
+    Some(romaji)
+}
+
+// Vowel of a small ya, yu or yo, which makes a digraph like `kya` with the kana before it
+fn small_y_vowel(c: char) -> Option<char> {
+    match c {
+        'ゃ' | 'ャ' => Some('a'),
+        'ゅ' | 'ュ' => Some('u'),
+        'ょ' | 'ョ' => Some('o'),
+        _ => None,
+    }
+}
+
+// Small vowels replace the vowel of the kana before them, like in `ファ` (`fa`)
+fn small_vowel(c: char) -> Option<char> {
+    match c {
+        'ぁ' | 'ァ' => Some('a'),
+        'ぃ' | 'ィ' => Some('i'),
+        'ぅ' | 'ゥ' => Some('u'),
+        'ぇ' | 'ェ' => Some('e'),
+        'ぉ' | 'ォ' => Some('o'),
+        _ => None,
+    }
+}
+
+// Replaces kana with romaji, everything else is left as it is
+pub(crate) fn romanize(s: &str) -> String {
+    let mut romaji = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    // After a small tsu, which doubles the following consonant
+    let mut double = false;
+    let mut prev_is_kana = false;
+    while let Some(c) = chars.next() {
+        if c == 'っ' || c == 'ッ' {
+            double = true;
+            prev_is_kana = true;
+            continue;
+        }
+        // The long vowel mark would be a macron, which ascii doesn't have
+        if c == 'ー' && prev_is_kana {
+            continue;
+        }
+        let base = match syllable(c) {
+            Some(base) => base,
+            None => {
+                double = false;
+                prev_is_kana = false;
+                romaji.push(c);
+                continue;
+            }
+        };
+        let mut syllable = base.to_string();
+        let next = chars.peek().copied();
+        if let Some(vowel) = next
+            .and_then(small_y_vowel)
+            .filter(|_| base.len() > 1 && base.ends_with('i'))
+        {
+            // `shi` + `ya` is `sha`, `ki` + `ya` is `kya`
+            syllable.pop();
+            if !matches!(base, "shi" | "chi" | "ji") {
+                syllable.push('y');
+            }
+            syllable.push(vowel);
+            chars.next();
+        } else if let Some(vowel) = next
+            .and_then(small_vowel)
+            .filter(|_| base.len() > 1 || base == "u")
+        {
+            if base == "u" {
+                syllable = "w".to_string();
+            } else {
+                syllable.pop();
+            }
+            syllable.push(vowel);
+            chars.next();
+        }
+        if double {
+            match syllable.as_bytes()[0] {
+                b'a' | b'e' | b'i' | b'o' | b'u' | b'n' => {}
+                // `tchi`, not `cchi`
+                b'c' => romaji.push('t'),
+                b => romaji.push(b as char),
+            }
+            double = false;
+        }
+        romaji.push_str(&syllable);
+        prev_is_kana = true;
+    }
+    romaji
+}
@@ -0,0 +1,86 @@
+use crate::slugify;
+use std::thread;
+
+/// Slugify a very large `s` in parallel, the result is exactly `slugify(s)`
+///
+/// `s` is split at the first ascii whitespace or punctuation after every `chunk_size` bytes,
+/// which is a word break anyway, and the chunks are slugified on one scoped thread per
+/// available core. The slugs are joined with a single `-`, chunks with an empty slug are
+/// skipped. Inputs of at most `chunk_size` bytes are slugified on the calling thread.
+///
+/// # Panics
+///
+/// When `chunk_size` is 0.
+///
+/// ```rust
+/// use slugmin::{slugify, slugify_large};
+///
+/// let titles = "My Post!\nÆúű--cool?\n!!!\n東京 🎉\n".repeat(1000);
+/// assert_eq!(slugify_large(&titles, 64), slugify(&titles));
+/// assert_eq!(slugify_large("a, b", 1), "a-b");
+/// assert_eq!(slugify_large("", 1), "");
+/// ```
+pub fn slugify_large<S: AsRef<str>>(s: S, chunk_size: usize) -> String {
+    _slugify_large(s.as_ref(), chunk_size)
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_large(s: &str, chunk_size: usize) -> String {
+    assert!(chunk_size > 0, "chunk_size must be positive");
+    let chunks = split_chunks(s, chunk_size);
+    if chunks.len() == 1 {
+        return slugify(s);
+    }
+    let threads = thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(chunks.len());
+    let chunks_per_thread = chunks.len().div_ceil(threads);
+    let parts: Vec<String> = thread::scope(|scope| {
+        let handles: Vec<_> = chunks
+            .chunks(chunks_per_thread)
+            .map(|group| scope.spawn(move || join_slugs(group.iter().map(slugify))))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("slugify doesn't panic"))
+            .collect()
+    });
+    join_slugs(parts)
+}
+
+// Splits `s` at an ascii character which is not a letter or digit after every `chunk_size`
+// bytes, the character itself is left out as it only separates words
+fn split_chunks(s: &str, chunk_size: usize) -> Vec<&str> {
+    let bytes = s.as_bytes();
+    let mut chunks = Vec::with_capacity(s.len() / chunk_size + 1);
+    let mut start = 0;
+    while s.len() - start > chunk_size {
+        let separator = bytes[start + chunk_size..]
+            .iter()
+            .position(|b| b.is_ascii() && !b.is_ascii_alphanumeric());
+        match separator {
+            Some(offset) => {
+                let split = start + chunk_size + offset;
+                chunks.push(&s[start..split]);
+                start = split + 1;
+            }
+            None => break,
+        }
+    }
+    chunks.push(&s[start..]);
+    chunks
+}
+
+fn join_slugs<I: IntoIterator<Item = String>>(slugs: I) -> String {
+    let mut joined = String::new();
+    for slug in slugs {
+        if slug.is_empty() {
+            continue;
+        }
+        if !joined.is_empty() {
+            joined.push('-');
+        }
+        joined.push_str(&slug);
+    }
+    joined
+}
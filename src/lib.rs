@@ -1,11 +1,81 @@
 extern crate deunicode;
 
-use deunicode::deunicode_char;
+use chars::transliterate;
+
+mod anchor;
+mod case;
+mod chars;
+mod class;
+mod compat;
+mod custom;
+mod handles;
+mod hash;
+#[cfg(feature = "icu")]
+mod icu;
+#[cfg(feature = "kana")]
+mod kana;
+mod large;
+mod lines;
+mod map;
+mod options;
+mod path;
+mod preset;
+mod reversible;
+mod roman;
+mod script;
+mod slug;
+mod sort;
+mod tokens;
+mod unique;
+
+pub use anchor::{
+    anchors_for, github_anchor, github_anchor_unique, pandoc_identifier, wiki_anchor,
+    AnchorGenerator,
+};
+pub use case::{
+    restyle, slug_and_title, slugify_camel, slugify_ci_safe, slugify_sentence_case,
+    slugify_train_case, titleize, CaseStyle,
+};
+pub use class::{slug_char_class, slug_trace, SlugCharClass};
+pub use compat::{parameterize, sanitize_title, slugify_django, slugify_query_value};
+pub use custom::{slugify_with_fallback, slugify_with_filter, slugify_with_protected};
+pub use handles::{
+    slugify_fedi_username, slugify_handle, slugify_irc_channel, slugify_slack_channel,
+    slugify_telegram_username,
+};
+pub use hash::{slug_cache_key, slug_shard, slugify_abbrev};
+pub use large::slugify_large;
+pub use lines::slugify_lines;
+#[doc(hidden)]
+pub use map::__sort_slug_map;
+pub use map::{slugify_with_map, SlugMap};
+pub use options::{
+    slugify_with, AtSign, EmojiMode, LatinStrategy, LeadingDigit, SlugOptions, Unmappable,
+};
+pub use path::{slugify_last_segment, slugify_path, slugify_path_with, EmptySegment};
+pub use preset::{is_valid_for, slugify_preset, Preset};
+pub use reversible::{reconstruct, slugify_reversible, SlugMeta};
+pub use script::{slugify_with_script, Script};
+pub use slug::{is_slug, slugify_validate, InvalidSlug, Slug};
+#[cfg(feature = "clap")]
+pub use slug::SlugValueParser;
+#[cfg(feature = "derive")]
+pub use slugmin_derive::Slugify;
+pub use sort::sort_key;
+pub use tokens::{
+    reslug_separator, slug_join, slug_tokens, slugify_chunks, slugify_hierarchy,
+    slugify_without_stop_words,
+};
+pub use unique::{split_slug_suffix, try_slugify_unique};
 
 /// Convert any unicode string to an ascii "slug" (useful for file names/url components)
 ///
 /// The returned "slug" will consist of a-z, 0-9, and '-'. Furthermore, a slug will
 /// never contain more than one '-' in a row and will never start or end with '-'.
+/// So whitespace and punctuation around `s`, like a `!` added to a heading, never change it.
+/// Right-to-left text is romanized in logical (memory) order, so the slug reads left to
+/// right, and bidi control characters are removed. Unicode dashes and minus signs separate
+/// words like `-`.
 ///
 /// ```rust
 /// use self::slugmin::slugify;
@@ -16,50 +86,178 @@ use deunicode::deunicode_char;
 /// assert_eq!(slugify("Æúű--cool?"), "aeuu-cool");
 /// assert_eq!(slugify("You & Me"), "you-me");
 /// assert_eq!(slugify("user@example.com"), "user-example-com");
+/// assert_eq!(slugify("½ cup, 20°C"), "1-2-cup-20degc");
+/// assert_eq!(slugify("東京! 🎉"), "dong-jing-tada");
+/// assert_eq!(slugify("§12 Penalties"), "section-12-penalties");
+/// assert_eq!(slugify("Məmmədov, Ǝkə"), "memmedov-eke");
+/// assert_eq!(slugify("x\u{1AC1}y z\u{20F0}"), "xy-z");
+/// assert_eq!(slugify("\u{202B}שָׁלוֹם עוֹלָם\u{202C}"), "shalvom-volam");
+/// assert_eq!(slugify("\u{2067}مدرسة\u{2069} \u{200F}2"), "mdrsh-2");
+/// assert_eq!(slugify("1990–1995"), "1990-1995");
+/// assert_eq!(slugify("a—b"), "a-b");
+/// assert_eq!(slugify("a - – — −b"), "a-b");
 /// ```
 pub fn slugify<S: AsRef<str>>(s: S) -> String {
     _slugify(s.as_ref())
 }
 
+/// Like `slugify`, but appends the slug to `out`, which allows reusing one buffer
+///
+/// ```rust
+/// use slugmin::slugify_into;
+///
+/// let mut out = String::from("posts/");
+/// slugify_into("My Test String!", &mut out);
+/// assert_eq!(out, "posts/my-test-string");
+///
+/// out.clear();
+/// slugify_into("!!!", &mut out);
+/// assert_eq!(out, "");
+/// ```
+pub fn slugify_into<S: AsRef<str>>(s: S, out: &mut String) {
+    _slugify_into(s.as_ref(), out)
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_into(s: &str, out: &mut String) {
+    if slug::_is_slug(s) {
+        out.push_str(s);
+        return;
+    }
+    let start = out.len();
+    out.reserve(s.len());
+    slug_bytes(s, |x| out.push(x as char));
+    if out.len() > start && out.ends_with('-') {
+        out.pop();
+    }
+}
+
+/// Like `slugify`, but returns `default` (slugified as well) instead of an empty slug
+///
+/// `slugify` returns an empty string when `s` contains no letters or numbers, which is
+/// surprising as an URL segment or file name.
+///
+/// ```rust
+/// use slugmin::slugify_or;
+///
+/// assert_eq!(slugify_or("---", "untitled"), "untitled");
+/// assert_eq!(slugify_or("!!!", "untitled"), "untitled");
+/// assert_eq!(slugify_or("   ", "untitled"), "untitled");
+/// assert_eq!(slugify_or("", "Untitled Page"), "untitled-page");
+/// assert_eq!(slugify_or("My Page", "untitled"), "my-page");
+/// ```
+pub fn slugify_or<S: AsRef<str>, D: AsRef<str>>(s: S, default: D) -> String {
+    _slugify_or(s.as_ref(), default.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_or(s: &str, default: &str) -> String {
+    let slug = _slugify(s);
+    if slug.is_empty() {
+        _slugify(default)
+    } else {
+        slug
+    }
+}
+
+/// Like `slugify`, but the slug ends with exactly one `-`, so another slug can be appended
+///
+/// The empty slug stays empty instead of becoming a lone `-`.
+///
+/// ```rust
+/// use slugmin::slugify_with_trailing_sep;
+///
+/// assert_eq!(slugify_with_trailing_sep("a b") + "c", "a-b-c");
+/// assert_eq!(slugify_with_trailing_sep("--Part 1!!--"), "part-1-");
+/// assert_eq!(slugify_with_trailing_sep(""), "");
+/// assert_eq!(slugify_with_trailing_sep("!!!"), "");
+/// ```
+pub fn slugify_with_trailing_sep<S: AsRef<str>>(s: S) -> String {
+    let mut slug = _slugify(s.as_ref());
+    if !slug.is_empty() {
+        slug.push('-');
+    }
+    slug
+}
+
+/// Like `slugify`, for bytes which should be UTF-8
+///
+/// Invalid UTF-8 is decoded lossily: every invalid sequence becomes U+FFFD REPLACEMENT
+/// CHARACTER, which is a word break like other punctuation, so `caf\xE9` is `caf`.
+///
+/// ```rust
+/// use slugmin::slugify_bytes;
+///
+/// assert_eq!(slugify_bytes(b"My Test String!"), "my-test-string");
+/// assert_eq!(slugify_bytes("Æúű--cool?".as_bytes()), "aeuu-cool");
+/// assert_eq!(slugify_bytes(b"caf\xE9 au lait"), "caf-au-lait");
+/// assert_eq!(slugify_bytes(b"\xFF\xFEab\xC3"), "ab");
+/// ```
+pub fn slugify_bytes<B: AsRef<[u8]>>(bytes: B) -> String {
+    _slugify(&String::from_utf8_lossy(bytes.as_ref()))
+}
+
+/// Slugify `s` prefixed with the `(year, month, day)` date as `YYYY-MM-DD`, like archive
+/// file names
+///
+/// # Panics
+///
+/// When the date doesn't exist or its year is not between 0 and 9999, which can't be written
+/// with four digits.
+///
+/// ```rust
+/// use slugmin::slugify_prefixed_date;
+///
+/// assert_eq!(slugify_prefixed_date("My Post!", (2024, 1, 5)), "2024-01-05-my-post");
+/// assert_eq!(slugify_prefixed_date("Release 2.0", (2023, 12, 31)), "2023-12-31-release-2-0");
+/// assert_eq!(slugify_prefixed_date("", (2024, 1, 15)), "2024-01-15");
+/// assert_eq!(slugify_prefixed_date("Leap", (2024, 2, 29)), "2024-02-29-leap");
+/// ```
+///
+/// ```rust,should_panic
+/// slugmin::slugify_prefixed_date("post", (-5, 1, 2));
+/// ```
+///
+/// ```rust,should_panic
+/// slugmin::slugify_prefixed_date("post", (2024, 13, 40));
+/// ```
+///
+/// ```rust,should_panic
+/// slugmin::slugify_prefixed_date("post", (2023, 2, 29));
+/// ```
+pub fn slugify_prefixed_date<S: AsRef<str>>(s: S, date: (i32, u32, u32)) -> String {
+    let (year, month, day) = date;
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    assert!(
+        (0..=9999).contains(&year) && (1..=12).contains(&month) && (1..=days).contains(&day),
+        "{:?} is not a date with a four digit year",
+        date
+    );
+    let mut slug = format!("{:04}-{:02}-{:02}-", year, month, day);
+    slugify_into(s, &mut slug);
+    if slug.ends_with('-') {
+        slug.pop();
+    }
+    slug
+}
+
 // avoid unnecessary monomorphizations
 fn _slugify(s: &str) -> String {
-    let mut slug: Vec<u8> = Vec::with_capacity(s.len());
-    // Starts with true to avoid leading -
-    let mut prev_is_dash = true;
-    {
-        let mut push_char = |x: u8| {
-            match x {
-                b'a'..=b'z' | b'0'..=b'9' => {
-                    prev_is_dash = false;
-                    slug.push(x);
-                }
-                b'A'..=b'Z' => {
-                    prev_is_dash = false;
-                    // Manual lowercasing as Rust to_lowercase() is unicode
-                    // aware and therefore much slower
-                    slug.push(x - b'A' + b'a');
-                }
-                _ => {
-                    if !prev_is_dash {
-                        slug.push(b'-');
-                        prev_is_dash = true;
-                    }
-                }
-            }
-        };
-
-        for c in s.chars() {
-            if c.is_ascii() {
-                (push_char)(c as u8);
-            } else {
-                for &cx in deunicode_char(c).unwrap_or("-").as_bytes() {
-                    (push_char)(cx);
-                }
-            }
-        }
+    // Already normalized input is common and can be copied as it is
+    if slug::_is_slug(s) {
+        return s.to_string();
     }
+    let mut slug: Vec<u8> = Vec::with_capacity(s.len());
+    slug_bytes(s, |x| slug.push(x));
 
-    // It's not really unsafe in practice, we know we have ASCII
+    // Every byte comes from `slug_bytes`, which only produces ascii, checked in debug builds
+    debug_assert!(slug.is_ascii(), "slug is not ascii: {:?}", slug);
     let mut string = unsafe { String::from_utf8_unchecked(slug) };
     if string.ends_with('-') {
         string.pop();
@@ -69,6 +267,215 @@ fn _slugify(s: &str) -> String {
     string
 }
 
+// Calls `push` with every byte of the slug of `s`, the last one may be a trailing `-`
+fn slug_bytes(s: &str, mut push: impl FnMut(u8)) {
+    slug_bytes_while(s, None, |x| {
+        push(x);
+        true
+    });
+}
+
+// Like `slug_bytes`, but stops as soon as `push` returns false and uses the replacements of
+// `map` before the transliteration
+pub(crate) fn slug_bytes_while(
+    s: &str,
+    map: Option<&SlugMap>,
+    mut push: impl FnMut(u8) -> bool,
+) {
+    // Starts with true to avoid leading -
+    let mut prev_is_dash = true;
+    let mut push_char = |x: u8| match x {
+        b'a'..=b'z' | b'0'..=b'9' => {
+            prev_is_dash = false;
+            push(x)
+        }
+        b'A'..=b'Z' => {
+            prev_is_dash = false;
+            // Manual lowercasing as Rust to_lowercase() is unicode
+            // aware and therefore much slower
+            push(x - b'A' + b'a')
+        }
+        _ => {
+            if prev_is_dash {
+                return true;
+            }
+            prev_is_dash = true;
+            push(b'-')
+        }
+    };
+
+    for c in s.chars() {
+        if !char_bytes(c, map, &mut push_char) {
+            return;
+        }
+    }
+}
+
+// Calls `push` with the bytes `c` is replaced by, before lowercasing and collapsing the
+// separators, until it returns false
+fn char_bytes(c: char, map: Option<&SlugMap>, push: &mut impl FnMut(u8) -> bool) -> bool {
+    if let Some(replacement) = map.and_then(|map| map.get(c)) {
+        replacement.bytes().all(push)
+    } else if c.is_ascii() {
+        push(c as u8)
+    } else if chars::is_dash(c) {
+        push(b'-')
+    } else if let Some(word) = chars::symbol_word(c) {
+        push(b'-') && word.bytes().all(&mut *push) && push(b'-')
+    } else {
+        transliterate(c).unwrap_or("-").bytes().all(push)
+    }
+}
+
+/// Length in bytes of `slugify(s)`, computed without allocating the slug
+///
+/// ```rust
+/// use slugmin::{slug_len, slugify};
+///
+/// assert_eq!(slug_len("My Test String!!!1!1"), 18);
+/// let corpus = [
+///     "", "-", "!!!", "  --test_-_cool", "Æúű--cool?", "You & Me", "user@example.com",
+///     "മലയാലമ്げんまい茶??", "a\u{E000}b", "trailing ½", "🎉 Party 🎉", "tab\tand\nnewline",
+/// ];
+/// for s in corpus.iter() {
+///     assert_eq!(slug_len(s), slugify(s).len(), "{:?}", s);
+/// }
+/// ```
+pub fn slug_len<S: AsRef<str>>(s: S) -> usize {
+    let mut len = 0;
+    let mut ends_with_dash = false;
+    slug_bytes(s.as_ref(), |x| {
+        len += 1;
+        ends_with_dash = x == b'-';
+    });
+    len - ends_with_dash as usize
+}
+
+/// Whether `slugify(input)` cut to at most `max` bytes is exactly `target`
+///
+/// Separators left at the end of the cut slug are removed, so `my-post-2` cut to 8 bytes
+/// matches `my-post`. The slug is compared while it is generated, without allocating, and the
+/// comparison stops at the first mismatch or at `max` bytes.
+///
+/// ```rust
+/// use slugmin::slug_eq_bounded;
+///
+/// assert!(slug_eq_bounded("My Post!", "my-post", 64));
+/// assert!(slug_eq_bounded("MY post", "my-post", 64));
+/// assert!(!slug_eq_bounded("My Posts", "my-post", 64));
+/// assert!(!slug_eq_bounded("My Post", "my-posts", 64));
+/// assert!(slug_eq_bounded("My Post 2", "my-post", 8));
+/// assert!(slug_eq_bounded("My Posts", "my-post", 7));
+/// assert!(!slug_eq_bounded("My Post", "my-post", 6));
+/// assert!(slug_eq_bounded("!!!", "", 10));
+/// ```
+pub fn slug_eq_bounded<S: AsRef<str>>(input: S, target: &str, max: usize) -> bool {
+    _slug_eq_bounded(input.as_ref(), target.as_bytes(), max)
+}
+
+// avoid unnecessary monomorphizations
+fn _slug_eq_bounded(input: &str, target: &[u8], max: usize) -> bool {
+    let mut len = 0;
+    let mut matched = 0;
+    // A `-` is only compared once the next byte shows that it's not trailing
+    let mut pending_dash = false;
+    let mut mismatch = false;
+    slug_bytes_while(input, None, |x| {
+        if len == max {
+            return false;
+        }
+        len += 1;
+        if x == b'-' {
+            pending_dash = true;
+            return true;
+        }
+        let expected: &[u8] = if pending_dash { &[b'-', x] } else { &[x] };
+        pending_dash = false;
+        if !target[matched..].starts_with(expected) {
+            mismatch = true;
+            return false;
+        }
+        matched += expected.len();
+        true
+    });
+    !mismatch && matched == target.len()
+}
+
+/// Like `slugify`, but the slug is cut to at most `max` bytes while it is generated
+///
+/// The scan stops as soon as `max` bytes of the slug are produced, so the rest of `s` is never
+/// transliterated, which bounds the work on untrusted input. Separators left at the end of the
+/// cut slug are removed, the result is the same as cutting `slugify(s)` and trimming it.
+///
+/// ```rust
+/// use slugmin::slugify_capped;
+///
+/// assert_eq!(slugify_capped("My Test String!!!1!1", 7), "my-test");
+/// assert_eq!(slugify_capped("My Test String!!!1!1", 8), "my-test");
+/// assert_eq!(slugify_capped("My Test String!!!1!1", 64), "my-test-string-1-1");
+/// assert_eq!(slugify_capped("Æúű--cool?", 2), "ae");
+/// assert_eq!(slugify_capped("My Post", 0), "");
+///
+/// // Only the first few characters of the input are looked at
+/// let pathological = "東".repeat(10_000_000);
+/// assert_eq!(slugify_capped(&pathological, 13), "dong-dong-don");
+/// ```
+pub fn slugify_capped<S: AsRef<str>>(s: S, max: usize) -> String {
+    _slugify_capped(s.as_ref(), max)
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_capped(s: &str, max: usize) -> String {
+    // `max` may be far above the length of the slug
+    let mut slug = String::with_capacity(max.min(s.len()));
+    slug_bytes_while(s, None, |x| {
+        if slug.len() == max {
+            return false;
+        }
+        slug.push(x as char);
+        true
+    });
+    if slug.ends_with('-') {
+        slug.pop();
+    }
+    slug
+}
+
+/// Slugify `s` to exactly `n` bytes, for keys of a fixed width
+///
+/// A longer slug is cut after the last whole word which fits, like with
+/// [`SlugOptions::max_length`](struct.SlugOptions.html#method.max_length), and a shorter one
+/// is padded with `pad` on the right. `pad` should be a character a slug never ends with,
+/// like `_`, `.` or `-`, so slugs stay apart and `trim_end_matches(pad)` gives the slug back.
+/// A letter or digit makes `post-1` padded with `0` look like `post-10`.
+///
+/// # Panics
+///
+/// When `pad` is not ascii, as the padding is counted in bytes.
+///
+/// ```rust
+/// use slugmin::slugify_fixed;
+///
+/// assert_eq!(slugify_fixed("My Post", 12, '_'), "my-post_____");
+/// assert_eq!(slugify_fixed("The history of Rome", 13, '_'), "the-history__");
+/// assert_eq!(slugify_fixed("The history of Rome", 11, '_'), "the-history");
+/// assert_eq!(slugify_fixed("Supercalifragilistic", 8, '_'), "supercal");
+/// assert_eq!(slugify_fixed("!!!", 3, '.'), "...");
+/// assert_eq!(slugify_fixed("My Post", 10, '-').trim_end_matches('-'), "my-post");
+/// ```
+pub fn slugify_fixed<S: AsRef<str>>(s: S, n: usize, pad: char) -> String {
+    _slugify_fixed(s.as_ref(), n, pad)
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_fixed(s: &str, n: usize, pad: char) -> String {
+    assert!(pad.is_ascii(), "pad must be an ascii character");
+    let mut slug = slugify_with(s, &SlugOptions::new().max_length(n));
+    let missing = n - slug.len();
+    slug.extend(std::iter::repeat_n(pad, missing));
+    slug
+}
+
 /// Convert any unicode string to an ascii "slug" (useful for file names/url components)
 /// In opposite to upper implementation, it removes also redundant whitespaces
 /// Allows also to not change size of letters
@@ -90,6 +497,11 @@ fn _slugify(s: &str) -> String {
 /// assert_eq!(slugify_normal("roman .txt",true), "roman .txt");
 /// assert_eq!(slugify_normal("roman. txt",true), "roman. txt");
 /// assert_eq!(slugify_normal("roman.  txt",true), "roman. txt");
+/// assert_eq!(slugify_normal("Page №5 ½ cup",true), "Page no 5 1-2 cup");
+/// assert_eq!(slugify_normal("東京 tower",false), "dong jing tower");
+/// assert_eq!(slugify_normal("東京!tower",false), "dong jing-tower");
+/// assert_eq!(slugify_normal("東京.txt",false), "dong jing.txt");
+/// assert_eq!(slugify_normal("🎉_party",false), "tada_party");
 /// ```
 pub fn slugify_normal<S: AsRef<str>>(s: S, leave_size : bool) -> String {
     _slugify_normal(s.as_ref(),leave_size)
@@ -97,85 +509,16 @@ pub fn slugify_normal<S: AsRef<str>>(s: S, leave_size : bool) -> String {
 
 // avoid unnecessary monomorphizations
 fn _slugify_normal(s: &str, leave_size : bool) -> String {
-    let mut slug: Vec<u8> = Vec::with_capacity(s.len());
-    // Starts with true to avoid leading -
-    let mut prev_is_dash = true;
-    let mut empty_space_was = true;
-    let mut dot_was_before = false;
-    {
-        let mut push_char = |x: u8| {
-            match x {
-                b'a'..=b'z' | b'0'..=b'9' => {
-                    prev_is_dash = false;
-                    dot_was_before = false;
-                    empty_space_was = false;
-                    slug.push(x);
-                }
-                b'A'..=b'Z' => {
-                    prev_is_dash = false;
-                    dot_was_before = false;
-                    empty_space_was = false;
-                    if leave_size {
-                        slug.push(x);
-                    } else {
-                        // Manual lowercasing as Rust to_lowercase() is unicode
-                        // aware and therefore much slower
-                        slug.push(x - b'A' + b'a');
-                    }
-                }
-                b' ' | b'_' => {
-                    if !empty_space_was {
-                        slug.push(x);
-                        prev_is_dash = false;
-                        dot_was_before = false;
-                        empty_space_was = true;
-                    }
-                }
-                b'.' => {
-                    if !dot_was_before  {
-                        slug.push(x);
-                        prev_is_dash = false;
-                        dot_was_before = true;
-                        empty_space_was = false;
-                    }
-                }
-                _ => {
-                    if !prev_is_dash {
-                        slug.push(b'-');
-                        prev_is_dash = true;
-                        dot_was_before = false;
-                        empty_space_was = false;
-                    }
-                }
-            }
-        };
-
-        for c in s.chars() {
-            if c.is_ascii() {
-                (push_char)(c as u8);
-            } else {
-                for &cx in deunicode_char(c).unwrap_or("-").as_bytes() {
-                    (push_char)(cx);
-                }
-            }
-        }
-    }
+    options::_slugify_with(s, &SlugOptions::normal(leave_size))
+}
 
-    // It's not really unsafe in practice, we know we have ASCII
-    let mut string = unsafe { String::from_utf8_unchecked(slug) };
-    // Removes from the end `-` and ` `
-    loop {
-        if string.ends_with('-') {
-            string.pop();
-            continue;
-        }
-        if string.ends_with(' ') {
-            string.pop();
-            continue;
-        }
-        break;
+// Calls `push` with the `%XX` encoding of every UTF-8 byte of `c`
+pub(crate) fn percent_encode(c: char, mut push: impl FnMut(u8)) {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    let mut buf = [0; 4];
+    for &b in c.encode_utf8(&mut buf).as_bytes() {
+        push(b'%');
+        push(HEX[(b >> 4) as usize]);
+        push(HEX[(b & 0xF) as usize]);
     }
-    // We likely reserved more space than needed.
-    string.shrink_to_fit();
-    string
 }
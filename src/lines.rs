@@ -0,0 +1,29 @@
+use crate::slugify_into;
+use std::io::{self, BufRead, Write};
+
+/// Slugify every line read from `reader` and write the slugs to `writer`, one per line
+///
+/// Both `\n` and `\r\n` line endings are accepted, the output always uses `\n`. The line
+/// and slug buffers are reused for all lines. Lines must be valid UTF-8. Like the rest of the
+/// crate it needs `std`, so it is always available without a feature.
+///
+/// ```rust
+/// use slugmin::slugify_lines;
+///
+/// let input = "Hello World\r\nÆúű--cool?\n\nlast line";
+/// let mut output = Vec::new();
+/// slugify_lines(input.as_bytes(), &mut output).unwrap();
+/// assert_eq!(String::from_utf8(output).unwrap(), "hello-world\naeuu-cool\n\nlast-line\n");
+/// ```
+pub fn slugify_lines<R: BufRead, W: Write>(mut reader: R, mut writer: W) -> io::Result<()> {
+    let mut line = String::new();
+    let mut slug = String::new();
+    while reader.read_line(&mut line)? != 0 {
+        slug.clear();
+        slugify_into(&line, &mut slug);
+        slug.push('\n');
+        writer.write_all(slug.as_bytes())?;
+        line.clear();
+    }
+    writer.flush()
+}
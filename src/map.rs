@@ -0,0 +1,121 @@
+/// Transliteration table built at compile time with [`slug_map!`](macro.slug_map.html)
+///
+/// The entries are sorted by character, so a lookup is a binary search.
+///
+/// ```rust
+/// use slugmin::{slug_map, SlugMap};
+///
+/// static GERMAN: SlugMap = slug_map! { 'ö' => "oe", 'ä' => "ae", 'ü' => "ue" };
+/// assert_eq!(GERMAN.get('ä'), Some("ae"));
+/// assert_eq!(GERMAN.get('ü'), Some("ue"));
+/// assert_eq!(GERMAN.get('a'), None);
+/// assert_eq!(GERMAN.len(), 3);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SlugMap {
+    entries: &'static [(char, &'static str)],
+}
+
+impl SlugMap {
+    // Used by `slug_map!`, the entries must be sorted
+    #[doc(hidden)]
+    pub const fn __from_sorted(entries: &'static [(char, &'static str)]) -> Self {
+        SlugMap { entries }
+    }
+
+    /// The replacement of `c`, if the map has one
+    pub fn get(&self, c: char) -> Option<&'static str> {
+        self.entries
+            .binary_search_by_key(&c, |&(key, _)| key)
+            .ok()
+            .map(|i| self.entries[i].1)
+    }
+
+    /// The number of characters with a replacement
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the map has no replacements
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+// Sorts the entries of `slug_map!` while compiling, duplicated characters fail the build
+#[doc(hidden)]
+pub const fn __sort_slug_map<const N: usize>(
+    mut entries: [(char, &'static str); N],
+) -> [(char, &'static str); N] {
+    // Insertion sort, as const fn can't use the slice sorts
+    let mut i = 1;
+    while i < N {
+        let mut j = i;
+        while j > 0 && entries[j - 1].0 as u32 >= entries[j].0 as u32 {
+            if entries[j - 1].0 as u32 == entries[j].0 as u32 {
+                panic!("slug_map! contains a character twice");
+            }
+            let entry = entries[j];
+            entries[j] = entries[j - 1];
+            entries[j - 1] = entry;
+            j -= 1;
+        }
+        i += 1;
+    }
+    entries
+}
+
+/// Build a [`SlugMap`](struct.SlugMap.html) of `char => "replacement"` entries at compile time
+///
+/// The entries may be written in any order, they are sorted while compiling and a character
+/// given twice is a compile error. The map can be used in a `static` or `const`.
+///
+/// ```rust
+/// use slugmin::{slug_map, slugify_with_map, SlugMap};
+///
+/// const MAP: SlugMap = slug_map! { 'ä' => "ae", 'ö' => "oe", '&' => "-and-" };
+/// assert_eq!(slugify_with_map("Schröder & Mädchen", &MAP), "schroeder-and-maedchen");
+/// assert!(slug_map! {}.is_empty());
+/// ```
+///
+/// ```compile_fail
+/// const MAP: slugmin::SlugMap = slugmin::slug_map! { 'ä' => "ae", 'ä' => "a" };
+/// ```
+#[macro_export]
+macro_rules! slug_map {
+    ($($c:expr => $replacement:expr),* $(,)?) => {{
+        const ENTRIES: &[(char, &str)] = &$crate::__sort_slug_map([$(($c, $replacement)),*]);
+        $crate::SlugMap::__from_sorted(ENTRIES)
+    }};
+}
+
+/// Convert any unicode string to a slug like `slugify`, using the replacements of `map` first
+///
+/// A replacement takes the place of the transliteration of its character, it is written into
+/// the word as it is, so `-` or ` ` in it separate words and everything except ascii letters
+/// and digits is a word break.
+///
+/// ```rust
+/// use slugmin::{slug_map, slugify, slugify_with_map, SlugMap};
+///
+/// static MAP: SlugMap = slug_map! { 'ü' => "ue", 'Ü' => "Ue", 'ß' => "ss", '+' => "plus" };
+/// assert_eq!(slugify_with_map("Grüße", &MAP), "gruesse");
+/// assert_eq!(slugify_with_map("C++ Über", &MAP), "cplusplus-ueber");
+/// assert_eq!(slugify("C++ Über"), "c-uber");
+/// ```
+pub fn slugify_with_map<S: AsRef<str>>(s: S, map: &SlugMap) -> String {
+    _slugify_with_map(s.as_ref(), map)
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_with_map(s: &str, map: &SlugMap) -> String {
+    let mut slug = String::with_capacity(s.len());
+    crate::slug_bytes_while(s, Some(map), |x| {
+        slug.push(x as char);
+        true
+    });
+    if slug.ends_with('-') {
+        slug.pop();
+    }
+    slug
+}
@@ -0,0 +1,1959 @@
+use crate::chars::{
+    is_dash, is_elided_format, is_emoji, is_list_separator, measurement_symbol, strip_marks,
+    symbol_word, transliterate, vanishes,
+};
+use crate::percent_encode;
+use crate::roman::replace_roman_numerals;
+use crate::script::{letter_script, Script};
+use std::borrow::Cow;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Configuration for [`slugify_with`](fn.slugify_with.html).
+///
+/// `SlugOptions::new()` produces the same output as `slugify`, `SlugOptions::normal(leave_size)`
+/// the same output as `slugify_normal`. Other options are set with the builder methods.
+///
+/// Slugifying a slug again with the same options returns it unchanged, except for the
+/// encoding of `Unmappable::PercentEncode`.
+///
+/// ```rust
+/// use slugmin::{slugify_with, SlugOptions};
+///
+/// assert_eq!(slugify_with("My Test String!!!1!1", &SlugOptions::new()), "my-test-string-1-1");
+/// assert_eq!(slugify_with("My Test String!!!1!1", &SlugOptions::normal(true)), "My Test String-1-1");
+/// ```
+#[derive(Clone, Debug)]
+pub struct SlugOptions {
+    normal: bool,
+    leave_size: bool,
+    unify_separators: Vec<char>,
+    internal_chars: Vec<u8>,
+    keep_underscore: bool,
+    output_alphabet: Option<Vec<u8>>,
+    collapse_separators: bool,
+    word_separator: u8,
+    punct_separator: Option<u8>,
+    max_dots: usize,
+    collapse_dot_space: bool,
+    dedupe_repeats: bool,
+    dedupe_words: bool,
+    abbreviate_words: Option<usize>,
+    overrides: Vec<(char, String)>,
+    unmappable: Unmappable,
+    emoji: EmojiMode,
+    at_sign: AtSign,
+    leading_digit: LeadingDigit,
+    mark_questions: Option<String>,
+    trim_chars: Option<Vec<char>>,
+    max_length: Option<usize>,
+    min_last_word_len: usize,
+    keep_trailing_number: bool,
+    max_separators: Option<usize>,
+    keep_decimals: bool,
+    decimal_separator: Option<u8>,
+    grouping_separator: Option<u8>,
+    keep_ip_addresses: bool,
+    keep_hex_colors: bool,
+    elide_middle_dot: bool,
+    colon_in_time: Option<u8>,
+    measurement_symbols: bool,
+    latin_strategy: LatinStrategy,
+    icu_transliteration: bool,
+    kana_romaji: bool,
+    roman_numerals: bool,
+    strip_list_prefix: bool,
+    strip_extensions: Vec<String>,
+}
+
+/// What happens to characters which are neither kept nor word breaks
+///
+/// Word breaks (ascii whitespace, `-`, `_` and the unified separators) always become the
+/// word separator, `-` by default.
+/// Everything else which is not kept, ascii punctuation as well as unicode characters
+/// without a transliteration, is handled by this strategy.
+///
+/// ```rust
+/// use slugmin::{slugify_with, SlugOptions, Unmappable};
+///
+/// let slug = |s: &str, unmappable: Unmappable| slugify_with(s, &SlugOptions::new().unmappable(unmappable));
+///
+/// assert_eq!(slug("Tom & Jerry!", Unmappable::Dash), "tom-jerry");
+/// assert_eq!(slug("it's a!!b", Unmappable::Dash), "it-s-a-b");
+///
+/// assert_eq!(slug("Tom & Jerry!", Unmappable::Delete), "tom-jerry");
+/// assert_eq!(slug("it's a!!b", Unmappable::Delete), "its-ab");
+///
+/// assert_eq!(slug("Tom & Jerry", Unmappable::Replace("and".to_string())), "tom-and-jerry");
+/// assert_eq!(slug("C++", Unmappable::Replace("plus".to_string())), "cplusplus");
+/// assert_eq!(slug("a!!b", Unmappable::Replace("-".to_string())), "a-b");
+///
+/// assert_eq!(slug("50% off!", Unmappable::PercentEncode), "50%25-off%21");
+/// assert_eq!(slug("a\u{E000}b", Unmappable::PercentEncode), "a%EE%80%80b");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Unmappable {
+    /// Emit the punctuation separator, runs of separators collapse into a single one
+    /// (the default)
+    #[default]
+    Dash,
+    /// Drop the character, it doesn't separate words so `a!b` becomes `ab`
+    Delete,
+    /// Emit the given string for every such character, runs are not collapsed.
+    /// The replacement itself goes through the usual rules, so a replacement of `-` collapses
+    /// like `Dash` and unmappable characters inside the replacement become `-`
+    Replace(String),
+    /// Emit the UTF-8 bytes of the character as `%XX`, runs are not collapsed.
+    /// Like any percent encoding it is not idempotent, a second pass encodes the `%` again
+    PercentEncode,
+}
+
+/// What happens to emoji, see [`SlugOptions::emoji`](struct.SlugOptions.html#method.emoji)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum EmojiMode {
+    /// Write every emoji as the words deunicode gives, `🎉` is `tada` (the default)
+    #[default]
+    Transliterate,
+    /// Drop emoji, they still separate the words around them
+    Delete,
+    /// Write a run of emoji as this word, once for the whole run
+    Placeholder(String),
+}
+
+/// What happens to `@`, see [`SlugOptions::at_sign`](struct.SlugOptions.html#method.at_sign)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum AtSign {
+    /// Handle it like other punctuation, which is a separator by default (the default)
+    #[default]
+    Dash,
+    /// Remove it without separating the words around it, for mentions like `@username`
+    Drop,
+    /// Write it as this word, like `at` for emails
+    Word(String),
+}
+
+/// What happens to a slug starting with a digit, see
+/// [`SlugOptions::leading_digit`](struct.SlugOptions.html#method.leading_digit)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LeadingDigit {
+    /// Keep it as it is (the default)
+    #[default]
+    Allow,
+    /// Remove everything before the first letter, a slug without letters becomes empty
+    Strip,
+    /// Start the slug with this ascii letter, `_` or `$`
+    Prefix(char),
+}
+
+/// How latin letters with diacritics are made ascii, see
+/// [`SlugOptions::latin_strategy`](struct.SlugOptions.html#method.latin_strategy)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LatinStrategy {
+    /// Transliterate them with deunicode, like every other character (the default)
+    #[default]
+    Deunicode,
+    /// Decompose them (NFD) and remove the combining marks, `ñ` is `n`
+    StripMarks,
+}
+
+impl Default for SlugOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SlugOptions {
+    /// Options matching `slugify`
+    pub fn new() -> Self {
+        SlugOptions {
+            normal: false,
+            leave_size: false,
+            unify_separators: Vec::new(),
+            internal_chars: Vec::new(),
+            keep_underscore: false,
+            output_alphabet: None,
+            collapse_separators: true,
+            word_separator: b'-',
+            punct_separator: None,
+            max_dots: 1,
+            collapse_dot_space: false,
+            dedupe_repeats: false,
+            dedupe_words: false,
+            abbreviate_words: None,
+            overrides: Vec::new(),
+            unmappable: Unmappable::Dash,
+            emoji: EmojiMode::Transliterate,
+            at_sign: AtSign::Dash,
+            leading_digit: LeadingDigit::Allow,
+            mark_questions: None,
+            trim_chars: None,
+            max_length: None,
+            min_last_word_len: 0,
+            keep_trailing_number: false,
+            max_separators: None,
+            keep_decimals: false,
+            decimal_separator: None,
+            grouping_separator: None,
+            keep_ip_addresses: false,
+            keep_hex_colors: false,
+            elide_middle_dot: false,
+            colon_in_time: None,
+            measurement_symbols: false,
+            latin_strategy: LatinStrategy::Deunicode,
+            icu_transliteration: false,
+            kana_romaji: false,
+            roman_numerals: false,
+            strip_list_prefix: false,
+            strip_extensions: Vec::new(),
+        }
+    }
+
+    /// Options matching `slugify_normal`, which keeps spaces, dots and underscores
+    ///
+    /// With `leave_size` transliterations follow the case of the original letter, a
+    /// lowercase letter gives lowercase letters and an uppercase one starts with an uppercase
+    /// letter. Umlauts lose their dots, `Ü` is `U` and not `Ue`.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::normal(true);
+    /// assert_eq!(slugify_with("Über ünd ÇOK", &options), "Uber und COK");
+    /// assert_eq!(slugify_with("Þór ŋ Ŋ", &options), "Thor ng Ng");
+    /// assert_eq!(slugify_with("ẞ ǅ Æsir", &options), "Ss Dz AEsir");
+    /// assert_eq!(slugify_with("Þór ŋ Ŋ", &SlugOptions::normal(false)), "thor ng ng");
+    /// ```
+    pub fn normal(leave_size: bool) -> Self {
+        SlugOptions {
+            normal: true,
+            leave_size,
+            ..Self::new()
+        }
+    }
+
+    /// Characters which are always treated as the `-` separator, even when they would
+    /// otherwise be kept (like `.` and `_` in normal mode)
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::normal(false).unify_separators(&['_', '.']);
+    /// assert_eq!(slugify_with("my_test.string", &options), "my-test-string");
+    /// assert_eq!(slugify_with("My_Test - .String", &options), "my-test - -string");
+    /// assert_eq!(slugify_with("_a..b_", &options), "a-b");
+    /// ```
+    pub fn unify_separators(mut self, chars: &[char]) -> Self {
+        self.unify_separators = chars.to_vec();
+        self
+    }
+
+    /// Characters which are part of the word before them, like `+` in `Na+`
+    ///
+    /// They are kept when they follow a letter, digit or another such character and are a
+    /// separator at the start of a word, so `E. coli` is `e.-coli` and `a + b` is `a-b`.
+    ///
+    /// # Panics
+    ///
+    /// When one of `chars` is not an ascii character.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().internal_chars(&['.', '+']);
+    /// assert_eq!(slugify_with("E. coli", &options), "e.-coli");
+    /// assert_eq!(slugify_with("Na+ and Cl", &options), "na+-and-cl");
+    /// assert_eq!(slugify_with("C++ vs C", &options), "c++-vs-c");
+    /// assert_eq!(slugify_with("a + b", &options), "a-b");
+    /// assert_eq!(slugify_with("+1 .net", &options), "1-net");
+    /// assert_eq!(slugify_with("E. coli", &SlugOptions::new()), "e-coli");
+    /// ```
+    pub fn internal_chars(mut self, chars: &[char]) -> Self {
+        assert!(
+            chars.iter().all(char::is_ascii),
+            "internal_chars must be ascii characters"
+        );
+        self.internal_chars = chars.iter().map(|&c| c as u8).collect();
+        self
+    }
+
+    /// Whether `_` is kept instead of becoming the word separator, like in normal mode but
+    /// without keeping spaces and dots
+    ///
+    /// Underscores at the start and the end of the slug are removed.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().keep_underscore(true);
+    /// assert_eq!(slugify_with("Hello_World!", &options), "hello_world");
+    /// assert_eq!(slugify_with("Hello_World!", &SlugOptions::new()), "hello-world");
+    /// assert_eq!(slugify_with("snake_case_name.rs", &options), "snake_case_name-rs");
+    /// assert_eq!(slugify_with("_private_", &options), "private");
+    /// assert_eq!(slugify_with("東京_tower", &options), "dong-jing_tower");
+    /// ```
+    pub fn keep_underscore(mut self, keep_underscore: bool) -> Self {
+        self.keep_underscore = keep_underscore;
+        self
+    }
+
+    /// The only letters and digits the slug may contain, for IDs without confusable
+    /// characters like `0` and `o`
+    ///
+    /// Letters and digits not in `alphabet` are removed, without separating the word around
+    /// them. Letters are compared ignoring case. Separators and the kept punctuation are not
+    /// affected, neither are percent encoded bytes.
+    ///
+    /// # Panics
+    ///
+    /// When one of `alphabet` is not an ascii character.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().output_alphabet(&[
+    ///     'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'j', 'k', 'm', 'n', 'p', 'q', 'r', 's', 't',
+    ///     'u', 'v', 'w', 'x', 'y', 'z', '2', '3', '4', '5', '6', '7', '8', '9',
+    /// ]);
+    /// assert_eq!(slugify_with("Room 101", &options), "rm");
+    /// assert_eq!(slugify_with("Good Food 2", &options), "gd-fd-2");
+    /// assert_eq!(slugify_with("Olive oil", &options), "ve");
+    /// assert_eq!(slugify_with("Good Food 2", &SlugOptions::new()), "good-food-2");
+    /// ```
+    pub fn output_alphabet(mut self, alphabet: &[char]) -> Self {
+        assert!(
+            alphabet.iter().all(char::is_ascii),
+            "output_alphabet must be ascii characters"
+        );
+        self.output_alphabet = Some(alphabet.iter().map(|&c| c as u8).collect());
+        self
+    }
+
+    /// Whether runs of separators become a single `-` (the default)
+    ///
+    /// When disabled every separator or punctuation character becomes its own `-`, so the
+    /// number of removed characters is preserved. Leading and trailing ones are still trimmed.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().collapse_separators(false);
+    /// assert_eq!(slugify_with("a & - b", &options), "a-----b");
+    /// assert_eq!(slugify_with("a!!!b", &options), "a---b");
+    /// assert_eq!(slugify_with("--a  b--", &options), "a--b");
+    /// assert_eq!(slugify_with("a & - b", &SlugOptions::new()), "a-b");
+    /// ```
+    pub fn collapse_separators(mut self, collapse_separators: bool) -> Self {
+        self.collapse_separators = collapse_separators;
+        self
+    }
+
+    /// Separator emitted for word breaks, whitespace, `-`, `_` and the unified separators
+    ///
+    /// `-` by default. The character itself is a word break too, so slugifying the output
+    /// again doesn't change it.
+    ///
+    /// # Panics
+    ///
+    /// When `c` is not ascii punctuation.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().word_separator('_');
+    /// assert_eq!(slugify_with("My Test-String", &options), "my_test_string");
+    /// assert_eq!(slugify_with("a b.c", &options), "a_b_c");
+    /// assert_eq!(slugify_with("a_b_c", &options), "a_b_c");
+    /// ```
+    pub fn word_separator(mut self, c: char) -> Self {
+        assert!(c.is_ascii_punctuation(), "word_separator must be ascii punctuation");
+        self.word_separator = c as u8;
+        self
+    }
+
+    /// Separator emitted where punctuation was removed by `Unmappable::Dash`
+    ///
+    /// The [word separator](#method.word_separator) by default. Setting it apart
+    /// tells where each break came from. In a run of both, like `, `, the punctuation wins.
+    /// The character itself is a punctuation break, so slugifying the output again doesn't
+    /// change it.
+    ///
+    /// # Panics
+    ///
+    /// When `c` is not ascii punctuation.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().punct_separator('_');
+    /// assert_eq!(slugify_with("a, b.c", &options), "a_b_c");
+    /// assert_eq!(slugify_with("a b", &options), "a-b");
+    /// assert_eq!(slugify_with("Tom & Jerry: the movie!", &options), "tom_jerry_the-movie");
+    /// assert_eq!(slugify_with("tom_jerry_the-movie", &options), "tom_jerry_the-movie");
+    /// ```
+    pub fn punct_separator(mut self, c: char) -> Self {
+        assert!(c.is_ascii_punctuation(), "punct_separator must be ascii punctuation");
+        self.punct_separator = Some(c as u8);
+        self
+    }
+
+    /// Maximum number of dots in a row kept in normal mode, 1 by default and 0 removes all dots
+    ///
+    /// In normal mode a run of spaces or underscores keeps only its first character and a run
+    /// of dots keeps at most `max_dots` dots. Any other character, including a space after a
+    /// dot, ends the run.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let slug = |s: &str, options: &SlugOptions| slugify_with(s, options);
+    /// let normal = SlugOptions::normal(false);
+    /// assert_eq!(slug("a.b", &normal), "a.b");
+    /// assert_eq!(slug("a. b", &normal), "a. b");
+    /// assert_eq!(slug("a..b", &normal), "a.b");
+    /// assert_eq!(slug("a. .b", &normal), "a. .b");
+    /// assert_eq!(slug("a.  .b", &normal), "a. .b");
+    /// assert_eq!(slug("a .. b", &normal), "a . b");
+    ///
+    /// let two = SlugOptions::normal(false).max_dots(2);
+    /// assert_eq!(slug("a..b", &two), "a..b");
+    /// assert_eq!(slug("a....b", &two), "a..b");
+    /// assert_eq!(slug("a. ..b", &two), "a. ..b");
+    ///
+    /// let none = SlugOptions::normal(false).max_dots(0);
+    /// assert_eq!(slug("a.b", &none), "ab");
+    /// assert_eq!(slug("a. b", &none), "a b");
+    /// assert_eq!(slug("file.tar.gz", &none), "filetargz");
+    /// ```
+    pub fn max_dots(mut self, max_dots: usize) -> Self {
+        self.max_dots = max_dots;
+        self
+    }
+
+    /// Whether spaces and underscores right after a dot are removed in normal mode, so that
+    /// `. ` becomes `.`
+    ///
+    /// The dot run continues after the removed space, so `. .` becomes a single `.` as well.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let slug = |s: &str| slugify_with(s, &SlugOptions::normal(true).collapse_dot_space(true));
+    /// assert_eq!(slug("a.b"), "a.b");
+    /// assert_eq!(slug("a. b"), "a.b");
+    /// assert_eq!(slug("a..b"), "a.b");
+    /// assert_eq!(slug("a. .b"), "a.b");
+    /// assert_eq!(slug("a.  .b"), "a.b");
+    /// assert_eq!(slug("a .. b"), "a .b");
+    /// assert_eq!(slug("roman._txt"), "roman.txt");
+    /// ```
+    pub fn collapse_dot_space(mut self, collapse_dot_space: bool) -> Self {
+        self.collapse_dot_space = collapse_dot_space;
+        self
+    }
+
+    /// Whether a letter repeated more than twice in a row is written only twice, so stylized
+    /// words like `coool` become `cool`
+    ///
+    /// Letters are compared ignoring case, digits are never changed.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().dedupe_repeats(true);
+    /// assert_eq!(slugify_with("sooo good", &options), "soo-good");
+    /// assert_eq!(slugify_with("Yesss!!! 1000 times", &options), "yess-1000-times");
+    /// assert_eq!(slugify_with("aaa-aaa", &options), "aa-aa");
+    /// assert_eq!(slugify_with("Zzz", &SlugOptions::normal(true).dedupe_repeats(true)), "Zz");
+    /// assert_eq!(slugify_with("sooo good", &SlugOptions::new()), "sooo-good");
+    /// ```
+    pub fn dedupe_repeats(mut self, dedupe_repeats: bool) -> Self {
+        self.dedupe_repeats = dedupe_repeats;
+        self
+    }
+
+    /// Whether a word equal to the word right before it is removed, so `new new product`
+    /// becomes `new-product`
+    ///
+    /// Words are compared ignoring case, only consecutive duplicates are removed, `a b a`
+    /// stays `a-b-a`. Numbers and addresses kept by other options are whole words.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().dedupe_words(true);
+    /// assert_eq!(slugify_with("the the end", &options), "the-end");
+    /// assert_eq!(slugify_with("ha ha funny ha", &options), "ha-funny-ha");
+    /// assert_eq!(slugify_with("New new NEW product!", &options), "new-product");
+    /// assert_eq!(slugify_with("the the end", &SlugOptions::new()), "the-the-end");
+    /// // Kept numbers and addresses are single words
+    /// let options = SlugOptions::new().dedupe_words(true).keep_decimals(true);
+    /// assert_eq!(slugify_with("pi 3.3 now", &options), "pi-3.3-now");
+    /// let options = SlugOptions::new().dedupe_words(true).keep_ip_addresses(true);
+    /// assert_eq!(slugify_with("host 10.10.0.1", &options), "host-10.10.0.1");
+    /// let options = SlugOptions::normal(true).dedupe_words(true);
+    /// assert_eq!(slugify_with("The the end", &options), "The end");
+    /// ```
+    pub fn dedupe_words(mut self, dedupe_words: bool) -> Self {
+        self.dedupe_words = dedupe_words;
+        self
+    }
+
+    /// Cuts every word to its first `len` characters, for compact codes like `int-bus`
+    ///
+    /// Numbers, words of only digits, stay whole, as a cut number means something else.
+    ///
+    /// # Panics
+    ///
+    /// When `len` is 0.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().abbreviate_words(3);
+    /// assert_eq!(slugify_with("International Business Machines", &options), "int-bus-mac");
+    /// assert_eq!(slugify_with("Report 2024 of a committee", &options), "rep-2024-of-a-com");
+    /// assert_eq!(slugify_with("Öffentliche Verkehrsmittel", &options), "off-ver");
+    /// let options = SlugOptions::normal(true).abbreviate_words(2);
+    /// assert_eq!(slugify_with("Quarterly review.pdf", &options), "Qu re.pd");
+    /// ```
+    pub fn abbreviate_words(mut self, len: usize) -> Self {
+        assert!(len > 0, "words must keep at least one character");
+        self.abbreviate_words = Some(len);
+        self
+    }
+
+    /// Writes `c` as the word `replacement`, separated from the neighbouring words
+    ///
+    /// Overrides are applied before the transliteration and the other rules, so they work
+    /// for ascii characters too. By default `§`, `¶`, `№` and `℅` are written as `section`,
+    /// `para`, `no` and `co`, which can be overridden as well.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// assert_eq!(slugify_with("§12 Penalties", &SlugOptions::new()), "section-12-penalties");
+    /// assert_eq!(slugify_with("see ¶3", &SlugOptions::normal(false)), "see para 3");
+    ///
+    /// let options = SlugOptions::new().override_char('&', "and").override_char('§', "paragraph");
+    /// assert_eq!(slugify_with("Tom&Jerry §2", &options), "tom-and-jerry-paragraph-2");
+    /// let options = SlugOptions::new().override_char('♥', "love");
+    /// assert_eq!(slugify_with("I♥NY", &options), "i-love-ny");
+    /// ```
+    pub fn override_char(mut self, c: char, replacement: &str) -> Self {
+        self.overrides.push((c, replacement.to_string()));
+        self
+    }
+
+    /// Strategy for characters which are not kept, see [`Unmappable`](enum.Unmappable.html)
+    ///
+    /// It is used for ascii punctuation and unicode characters without a transliteration alike
+    /// and composes with the other options. `max_length` never cuts a percent encoded
+    /// character in half.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions, Unmappable};
+    ///
+    /// let options = SlugOptions::new().unmappable(Unmappable::PercentEncode);
+    /// assert_eq!(slugify_with("50%! off", &options), "50%25%21-off");
+    /// assert_eq!(slugify_with("50%! off", &options.clone().max_length(10)), "50%25%21");
+    /// assert_eq!(slugify_with("50%! off", &options.clone().max_length(7)), "50%25");
+    /// assert_eq!(slugify_with("a\u{E000}b", &options.clone().max_length(6)), "a");
+    /// assert_eq!(slugify_with("a\u{E000}b", &options.max_length(10)), "a%EE%80%80");
+    ///
+    /// let options = SlugOptions::normal(true).unmappable(Unmappable::Delete).max_length(9);
+    /// assert_eq!(slugify_with("Tom & Jerry\u{E000}!", &options), "Tom Jerry");
+    /// ```
+    pub fn unmappable(mut self, unmappable: Unmappable) -> Self {
+        self.unmappable = unmappable;
+        self
+    }
+
+    /// Strategy for emoji and pictographic symbols, see [`EmojiMode`](enum.EmojiMode.html)
+    ///
+    /// A run of emoji is everything from an emoji up to the next character which is not an
+    /// emoji, a variation selector or a zero width joiner, so `👩‍💻` or `🎉🎉🎉` are one run.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, EmojiMode, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().emoji(EmojiMode::Placeholder("emoji".to_string()));
+    /// assert_eq!(slugify_with("Party 🎉🎉", &options), "party-emoji");
+    /// assert_eq!(slugify_with("Party 🎉🎉🎉!", &options), "party-emoji");
+    /// assert_eq!(slugify_with("🎉 party 👩‍💻", &options), "emoji-party-emoji");
+    /// assert_eq!(slugify_with("Party 🎉🎉", &SlugOptions::new()), "party-tada-tada");
+    ///
+    /// let options = SlugOptions::new().emoji(EmojiMode::Delete);
+    /// assert_eq!(slugify_with("Party 🎉🎉", &options), "party");
+    /// assert_eq!(slugify_with("a🎉b", &options), "a-b");
+    /// let options = SlugOptions::normal(true).emoji(EmojiMode::Placeholder("e".to_string()));
+    /// assert_eq!(slugify_with("Party🎉🎉", &options), "Party e");
+    /// ```
+    pub fn emoji(mut self, emoji: EmojiMode) -> Self {
+        self.emoji = emoji;
+        self
+    }
+
+    /// Handling of `@`, which separates the words of an email but starts a mention, see
+    /// [`AtSign`](enum.AtSign.html)
+    ///
+    /// Overrides of `@` take precedence.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, AtSign, SlugOptions};
+    ///
+    /// let slug = |s: &str, at_sign| slugify_with(s, &SlugOptions::new().at_sign(at_sign));
+    /// assert_eq!(slug("@handle", AtSign::Dash), "handle");
+    /// assert_eq!(slug("a@b", AtSign::Dash), "a-b");
+    /// assert_eq!(slug("@handle", AtSign::Drop), "handle");
+    /// assert_eq!(slug("ping @handle", AtSign::Drop), "ping-handle");
+    /// assert_eq!(slug("a@b", AtSign::Drop), "ab");
+    /// assert_eq!(slug("a@b", AtSign::Word("at".to_string())), "a-at-b");
+    /// assert_eq!(slug("user@example.com", AtSign::Word("at".to_string())), "user-at-example-com");
+    /// ```
+    pub fn at_sign(mut self, at_sign: AtSign) -> Self {
+        self.at_sign = at_sign;
+        self
+    }
+
+    /// Handling of a leading digit, which CSS or JavaScript identifiers and environment
+    /// variables don't allow, see [`LeadingDigit`](enum.LeadingDigit.html)
+    ///
+    /// It is applied before `max_length`, so a prefix counts into the length.
+    ///
+    /// # Panics
+    ///
+    /// When the character of `LeadingDigit::Prefix` is not an ascii letter, `_` or `$`, as
+    /// the slug would still not be a valid identifier.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, LeadingDigit, SlugOptions};
+    ///
+    /// let slug = |s: &str, leading_digit| {
+    ///     slugify_with(s, &SlugOptions::new().leading_digit(leading_digit))
+    /// };
+    /// assert_eq!(slug("3d model", LeadingDigit::Allow), "3d-model");
+    /// assert_eq!(slug("3d model", LeadingDigit::Strip), "d-model");
+    /// assert_eq!(slug("3d model", LeadingDigit::Prefix('_')), "_3d-model");
+    /// assert_eq!(slug("_3d-model", LeadingDigit::Prefix('_')), "_3d-model");
+    /// assert_eq!(slug("3 little pigs", LeadingDigit::Strip), "little-pigs");
+    /// assert_eq!(slug("2024", LeadingDigit::Strip), "");
+    /// assert_eq!(slug("model 3", LeadingDigit::Prefix('x')), "model-3");
+    /// assert_eq!(slug("1st place", LeadingDigit::Prefix('$')), "$1st-place");
+    /// ```
+    ///
+    /// ```should_panic
+    /// use slugmin::{LeadingDigit, SlugOptions};
+    ///
+    /// // `-3d` would start with a separator
+    /// SlugOptions::new().leading_digit(LeadingDigit::Prefix('-'));
+    /// ```
+    pub fn leading_digit(mut self, leading_digit: LeadingDigit) -> Self {
+        if let LeadingDigit::Prefix(c) = leading_digit {
+            assert!(
+                c.is_ascii_alphabetic() || c == '_' || c == '$',
+                "the leading digit prefix must be an ascii letter, `_` or `$`"
+            );
+        }
+        self.leading_digit = leading_digit;
+        self
+    }
+
+    /// Word appended to the slug of a question, an input ending with `?`, like FAQ entries
+    ///
+    /// Only a `?` at the end, optionally followed by whitespace, marks a question, any other
+    /// `?` is a separator as usual. The marker goes through the usual rules.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().mark_questions("q");
+    /// assert_eq!(slugify_with("How to bake?", &options), "how-to-bake-q");
+    /// assert_eq!(slugify_with("Why? Because", &options), "why-because");
+    /// assert_eq!(slugify_with("How to bake?", &SlugOptions::new()), "how-to-bake");
+    /// assert_eq!(slugify_with("?", &options), "");
+    /// assert_eq!(slugify_with("Is it ready??", &SlugOptions::normal(true).mark_questions("FAQ")), "Is it ready FAQ");
+    /// ```
+    pub fn mark_questions(mut self, marker: &str) -> Self {
+        self.mark_questions = Some(marker.to_string());
+        self
+    }
+
+    /// Characters removed from both ends of the slug
+    ///
+    /// By default leading `-`, ` ` and `_` are never emitted and trailing `-` and ` ` are
+    /// removed. When set, exactly the given characters are removed from both ends instead.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// assert_eq!(slugify_with(".name.", &SlugOptions::normal(false)), ".name.");
+    /// let options = SlugOptions::normal(false).trim_chars(&['.', '_', '-', ' ']);
+    /// assert_eq!(slugify_with(".name.", &options), "name");
+    /// assert_eq!(slugify_with(" _.-name_ .", &options), "name");
+    ///
+    /// let options = SlugOptions::new().trim_chars(&['.']);
+    /// assert_eq!(slugify_with("-name-", &options), "-name-");
+    /// ```
+    pub fn trim_chars(mut self, chars: &[char]) -> Self {
+        self.trim_chars = Some(chars.to_vec());
+        self
+    }
+
+    /// Maximum length of the slug in bytes
+    ///
+    /// Longer slugs are cut after the last whole word which fits, a single word longer than
+    /// `max_length` is cut in the middle. Separators left at the end are removed. Numbers and
+    /// addresses kept by other options, like `3.14`, are whole words.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().max_length(13);
+    /// assert_eq!(slugify_with("The history of Rome", &options), "the-history");
+    /// assert_eq!(slugify_with("The history", &options), "the-history");
+    /// assert_eq!(slugify_with("Supercalifragilistic", &options), "supercalifrag");
+    /// let options = SlugOptions::new().max_length(15).keep_ip_addresses(true);
+    /// assert_eq!(slugify_with("host 192.168.100.1", &options), "host");
+    /// let options = SlugOptions::new().max_length(9).keep_decimals(true);
+    /// assert_eq!(slugify_with("price 3.14159 each", &options), "price");
+    /// let options = SlugOptions::normal(true).max_length(10);
+    /// assert_eq!(slugify_with("Read me.txt now", &options), "Read me");
+    /// ```
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Maximum number of word breaks in the slug, the words after the last allowed break are
+    /// joined without a separator instead of being cut
+    ///
+    /// The first `max_separators` breaks are kept, counted from the start, so `a b c d` with
+    /// 2 is `a-b-cd`. A run of separators, like ` .` in the normal mode, is a single break.
+    /// Numbers and addresses kept by other options, like `3.14` or `10.0.0.1`, are never
+    /// joined with the words around them, as they would not be recognized again, and the
+    /// breaks around them don't count.
+    /// It is applied before `max_length`, which cuts the joined words like any other word.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().max_separators(2);
+    /// assert_eq!(slugify_with("a b c d", &options), "a-b-cd");
+    /// assert_eq!(slugify_with("a b", &options), "a-b");
+    /// assert_eq!(slugify_with("!!!The big, bad wolf!!!", &options), "the-big-badwolf");
+    /// let options = SlugOptions::new().max_separators(0);
+    /// assert_eq!(slugify_with("My Test String", &options), "myteststring");
+    /// let options = SlugOptions::new().max_separators(1).keep_decimals(true);
+    /// assert_eq!(slugify_with("price 3.14 each", &options), "price-3.14-each");
+    /// assert_eq!(slugify_with("the price 3.14 each time", &options), "the-price-3.14-eachtime");
+    /// let options = SlugOptions::new().max_separators(0).keep_ip_addresses(true);
+    /// assert_eq!(slugify_with("ping my host 10.0.0.1", &options), "pingmyhost-10.0.0.1");
+    /// let options = SlugOptions::normal(true).max_separators(1);
+    /// assert_eq!(slugify_with("Read me now.txt", &options), "Read menowtxt");
+    /// ```
+    pub fn max_separators(mut self, max_separators: usize) -> Self {
+        self.max_separators = Some(max_separators);
+        self
+    }
+
+    /// Minimal length of the last word left by [`max_length`](#method.max_length)
+    ///
+    /// Shorter last words are dropped as well, until the last word is long enough or only one
+    /// word is left, so no dangling `a` or `of` ends a truncated slug.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let title = "The history of a nation";
+    /// assert_eq!(slugify_with(title, &SlugOptions::new().max_length(19)), "the-history-of-a");
+    /// let options = SlugOptions::new().max_length(19).min_last_word_len(2);
+    /// assert_eq!(slugify_with(title, &options), "the-history-of");
+    /// let options = SlugOptions::new().max_length(19).min_last_word_len(3);
+    /// assert_eq!(slugify_with(title, &options), "the-history");
+    /// assert_eq!(slugify_with(title, &options.clone().max_length(100)), "the-history-of-a-nation");
+    /// ```
+    pub fn min_last_word_len(mut self, min_last_word_len: usize) -> Self {
+        self.min_last_word_len = min_last_word_len;
+        self
+    }
+
+    /// Whether [`max_length`](#method.max_length) keeps a number together with the word after
+    /// it, so a truncated slug never ends with a count whose noun was cut away
+    ///
+    /// A number, a word of only digits, which ends the truncated slug is dropped as well when
+    /// another word followed it, like `10` of `10-tips`. It's kept when it is the only word
+    /// left. This applies together with `min_last_word_len`.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let title = "part 2 of the series";
+    /// let options = SlugOptions::new().keep_trailing_number(true);
+    /// assert_eq!(slugify_with(title, &SlugOptions::new().max_length(8)), "part-2");
+    /// assert_eq!(slugify_with(title, &options.clone().max_length(8)), "part");
+    /// assert_eq!(slugify_with(title, &options.clone().max_length(9)), "part-2-of");
+    /// let options = options.min_last_word_len(3).max_length(10);
+    /// assert_eq!(slugify_with(title, &options), "part");
+    /// let options = SlugOptions::new().keep_trailing_number(true).max_length(6);
+    /// assert_eq!(slugify_with("2024 in review", &options), "2024");
+    /// assert_eq!(slugify_with("top 10 tips", &options), "top");
+    /// ```
+    pub fn keep_trailing_number(mut self, keep_trailing_number: bool) -> Self {
+        self.keep_trailing_number = keep_trailing_number;
+        self
+    }
+
+    /// Keeps a decimal point, a single `.` between digits of a number
+    ///
+    /// Numbers with more than one dot, like versions or dates, are not decimals and are
+    /// separated as usual.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().keep_decimals(true);
+    /// assert_eq!(slugify_with("price 3.14 each", &options), "price-3.14-each");
+    /// assert_eq!(slugify_with("version 1.2.3", &options), "version-1-2-3");
+    /// assert_eq!(slugify_with("Chapter 3. 14 pages", &options), "chapter-3-14-pages");
+    /// assert_eq!(slugify_with("price 3.14 each", &SlugOptions::new()), "price-3-14-each");
+    /// ```
+    pub fn keep_decimals(mut self, keep_decimals: bool) -> Self {
+        self.keep_decimals = keep_decimals;
+        self
+    }
+
+    /// Character used as the decimal point of numbers instead of `.`, like `,` in most of
+    /// Europe
+    ///
+    /// A single such character in a number is kept when `keep_decimals` is set and removed
+    /// otherwise, so the number is not split in two words.
+    ///
+    /// # Panics
+    ///
+    /// When `c` is not an ascii character.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let german = SlugOptions::new().decimal_separator(',').grouping_separator('.');
+    /// assert_eq!(slugify_with("Preis 1.234,56 EUR", &german), "preis-123456-eur");
+    /// assert_eq!(slugify_with("Preis 1.234,56 EUR", &german.keep_decimals(true)), "preis-1234,56-eur");
+    /// assert_eq!(slugify_with("pi 3,14", &SlugOptions::new().decimal_separator(',')), "pi-314");
+    /// assert_eq!(slugify_with("pi 3,14", &SlugOptions::new()), "pi-3-14");
+    /// ```
+    pub fn decimal_separator(mut self, c: char) -> Self {
+        assert!(c.is_ascii(), "decimal_separator must be an ascii character");
+        self.decimal_separator = Some(c as u8);
+        self
+    }
+
+    /// Character grouping digits of numbers, like `,` in `1,000,000`, which is removed
+    ///
+    /// Only a character followed by exactly three digits is a grouping separator, so
+    /// `1.2.3` is not a number with `.` grouping.
+    ///
+    /// # Panics
+    ///
+    /// When `c` is not an ascii character.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().grouping_separator(',');
+    /// assert_eq!(slugify_with("1,000,000 users", &options), "1000000-users");
+    /// assert_eq!(slugify_with("1,5 and 1,2345", &options), "1-5-and-1-2345");
+    /// let options = SlugOptions::new().grouping_separator('.');
+    /// assert_eq!(slugify_with("version 1.2.3", &options), "version-1-2-3");
+    /// ```
+    pub fn grouping_separator(mut self, c: char) -> Self {
+        assert!(c.is_ascii(), "grouping_separator must be an ascii character");
+        self.grouping_separator = Some(c as u8);
+        self
+    }
+
+    /// Keeps IPv4 and IPv6 address literals together
+    ///
+    /// IPv4 addresses are kept as they are. `:` is not slug safe, so IPv6 addresses are
+    /// written in full, as 8 groups of 4 lowercase hex digits separated by `.` like IPv4, and
+    /// this form is recognized again. An IPv6 address must contain a digit and have groups
+    /// on both sides of `::`, so `a::b`, `Foo::Bar` or a lone `::` are not taken for one.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().keep_ip_addresses(true);
+    /// assert_eq!(slugify_with("host 192.168.0.1 down", &options), "host-192.168.0.1-down");
+    /// let slug = slugify_with("ping 2001:DB8::FF00:42:8329!", &options);
+    /// assert_eq!(slug, "ping-2001.0db8.0000.0000.0000.ff00.0042.8329");
+    /// assert_eq!(slugify_with(&slug, &options), slug);
+    /// assert_eq!(slugify_with("fe80::1%eth0", &options), "fe80.0000.0000.0000.0000.0000.0000.0001-eth0");
+    /// assert_eq!(slugify_with("Reach 10.0.0.1.", &options), "reach-10.0.0.1");
+    /// assert_eq!(slugify_with("not 999.1.1.1 or a:b", &options), "not-999-1-1-1-or-a-b");
+    /// assert_eq!(slugify_with("a :: b, a-::-b, a::b", &options), "a-b-a-b-a-b");
+    /// assert_eq!(slugify_with("Foo::Bar 2::x", &options), "foo-bar-2-x");
+    /// assert_eq!(slugify_with("host 192.168.0.1 down", &SlugOptions::new()), "host-192-168-0-1-down");
+    /// ```
+    pub fn keep_ip_addresses(mut self, keep_ip_addresses: bool) -> Self {
+        self.keep_ip_addresses = keep_ip_addresses;
+        self
+    }
+
+    /// Keeps hex color codes, a `#` followed by 3 or 6 hex digits, as a single word without
+    /// the `#`
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().keep_hex_colors(true);
+    /// assert_eq!(slugify_with("Color #FF5733 on #FFF", &options), "color-ff5733-on-fff");
+    /// assert_eq!(slugify_with("Red#f00!", &options), "red-f00");
+    /// assert_eq!(slugify_with("Issue #1234 and #12", &options), "issue-1234-and-12");
+    /// assert_eq!(slugify_with("#BADCAFE", &options), "badcafe");
+    /// assert_eq!(slugify_with("Color #FF5733", &SlugOptions::normal(true).keep_hex_colors(true)), "Color FF5733");
+    /// ```
+    pub fn keep_hex_colors(mut self, keep_hex_colors: bool) -> Self {
+        self.keep_hex_colors = keep_hex_colors;
+        self
+    }
+
+    /// Removes a middle dot `·` between letters, like in the Catalan `l·l`, instead of
+    /// separating the words
+    ///
+    /// Middle dots which are not between letters and bullets like `•`, `‣` and `▪` always
+    /// separate words, whatever the [`Unmappable`](enum.Unmappable.html) strategy is.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions, Unmappable};
+    ///
+    /// let options = SlugOptions::new().elide_middle_dot(true);
+    /// assert_eq!(slugify_with("paral·lel", &options), "parallel");
+    /// assert_eq!(slugify_with("paral·lel", &SlugOptions::new()), "paral-lel");
+    /// assert_eq!(slugify_with("a · b", &options), "a-b");
+    /// assert_eq!(slugify_with("1·2", &options), "1-2");
+    ///
+    /// let options = SlugOptions::new().unmappable(Unmappable::Delete);
+    /// assert_eq!(slugify_with("tea•coffee‣milk▪water·juice", &options), "tea-coffee-milk-water-juice");
+    /// ```
+    pub fn elide_middle_dot(mut self, elide_middle_dot: bool) -> Self {
+        self.elide_middle_dot = elide_middle_dot;
+        self
+    }
+
+    /// Character emitted for a `:` between digits, like in `14:30`
+    ///
+    /// The colon is always replaced by exactly this character, independently of the
+    /// [`Unmappable`](enum.Unmappable.html) strategy used for other punctuation. The same
+    /// character between digits is kept as well, so slugifying the output again doesn't change it.
+    ///
+    /// # Panics
+    ///
+    /// When `c` is not an ascii character.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions, Unmappable};
+    ///
+    /// let options = SlugOptions::new().colon_in_time('-');
+    /// assert_eq!(slugify_with("meeting at 14:30", &options), "meeting-at-14-30");
+    /// assert_eq!(slugify_with("09:05:00", &options), "09-05-00");
+    /// assert_eq!(slugify_with("09:05:00", &options.clone().unmappable(Unmappable::Delete)), "09-05-00");
+    /// assert_eq!(slugify_with("09:05:00", &SlugOptions::new().colon_in_time('.')), "09.05.00");
+    /// assert_eq!(slugify_with("09.05.00", &SlugOptions::new().colon_in_time('.')), "09.05.00");
+    /// assert_eq!(slugify_with("Note: 09:05", &SlugOptions::new().colon_in_time('h')), "note-09h05");
+    /// ```
+    pub fn colon_in_time(mut self, c: char) -> Self {
+        assert!(c.is_ascii(), "colon_in_time must be an ascii character");
+        self.colon_in_time = Some(c as u8);
+        self
+    }
+
+    /// Writes the symbols of measurements consistently, `±` as `pm`, `°` as `deg`, `×` as `x`
+    /// and `µ` as `u`
+    ///
+    /// The degree stays attached to the number before it, so `25°C` is `25deg-c` and not
+    /// `25degc`, and `±` is a word of its own instead of a separator. `∓` is `mp`, `℃` and
+    /// `℉` are written like `°C` and `°F`. Overrides of these characters take precedence.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().measurement_symbols(true);
+    /// assert_eq!(slugify_with("25°C ±2", &options), "25deg-c-pm-2");
+    /// assert_eq!(slugify_with("25°C ±2", &SlugOptions::new()), "25degc-2");
+    /// assert_eq!(slugify_with("Melting at 451℉", &options), "melting-at-451deg-f");
+    /// assert_eq!(slugify_with("Angle of 90°", &options), "angle-of-90deg");
+    /// assert_eq!(slugify_with("A 3×4 grid", &options), "a-3x4-grid");
+    /// assert_eq!(slugify_with("Filter 0.2µm", &options), "filter-0-2um");
+    /// assert_eq!(slugify_with("v = 5 ∓ 1", &options), "v-5-mp-1");
+    /// let options = options.override_char('±', "plus-minus");
+    /// assert_eq!(slugify_with("25°C ±2", &options), "25deg-c-plus-minus-2");
+    /// ```
+    pub fn measurement_symbols(mut self, measurement_symbols: bool) -> Self {
+        self.measurement_symbols = measurement_symbols;
+        self
+    }
+
+    /// How latin letters with diacritics are made ascii, with deunicode or by stripping the
+    /// combining marks of their canonical decomposition (NFD), which skips the table lookup
+    ///
+    /// Both give the same letters for the precomposed latin letters. Letters which don't
+    /// decompose into an ascii letter and marks, like `ł`, `ø` or `æ`, and all other scripts
+    /// are still transliterated with deunicode.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, LatinStrategy, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().latin_strategy(LatinStrategy::StripMarks);
+    /// assert_eq!(slugify_with("Señor Müller", &options), "senor-muller");
+    /// assert_eq!(slugify_with("Señor Müller", &SlugOptions::new()), "senor-muller");
+    /// assert_eq!(slugify_with("Łódź", &options), "lodz");
+    /// assert_eq!(slugify_with("Łódź", &SlugOptions::new()), "lodz");
+    /// assert_eq!(slugify_with("Ærø, Ελλάδα", &options), "aero-ellada");
+    /// let options = SlugOptions::normal(true).latin_strategy(LatinStrategy::StripMarks);
+    /// assert_eq!(slugify_with("Ñandú.txt", &options), "Nandu.txt");
+    /// ```
+    pub fn latin_strategy(mut self, latin_strategy: LatinStrategy) -> Self {
+        self.latin_strategy = latin_strategy;
+        self
+    }
+
+    /// Transliterates with the ICU4X (CLDR) transforms to latin and then Latin-ASCII before
+    /// the usual transliteration, which gives far better results for scripts like Arabic or
+    /// Devanagari. Scripts without a transform in the ICU4X data (like Thai) and everything
+    /// left are handled by deunicode, which is also used for everything when the `icu`
+    /// feature is disabled. Soft hyphens and word joiners are removed before the transforms,
+    /// which would turn a soft hyphen into `-`.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().icu_transliteration(true);
+    /// # #[cfg(feature = "icu")]
+    /// # {
+    /// assert_eq!(slugify_with("مرحبا بالعالم", &options), "mrhba-bal-alm");
+    /// assert_eq!(slugify_with("مرحبا بالعالم", &SlugOptions::new()), "mrhb-bllm");
+    /// assert_eq!(slugify_with("नमस्ते दुनिया", &options), "namaste-duniya");
+    /// assert_eq!(slugify_with("مرح\u{AD}با", &options), "mrhba");
+    /// # }
+    /// assert_eq!(slugify_with("Æúű--cool?", &options), "aeuu-cool");
+    /// assert_eq!(slugify_with("doc\u{AD}ument", &options), "document");
+    /// ```
+    pub fn icu_transliteration(mut self, icu_transliteration: bool) -> Self {
+        self.icu_transliteration = icu_transliteration;
+        self
+    }
+
+    /// Writes Japanese hiragana and katakana in Hepburn romaji, with digraphs like `しゃ`
+    /// (`sha`) and the small `っ` doubling the next consonant
+    ///
+    /// Long vowels lose their macron, `ー` is dropped and vowels written with kana are kept
+    /// as written. Kanji are still transliterated by deunicode, which is also used for kana
+    /// when the `kana` feature is disabled. Takes precedence over `icu_transliteration`.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().kana_romaji(true);
+    /// # #[cfg(feature = "kana")]
+    /// # {
+    /// assert_eq!(slugify_with("しゃしん", &options), "shashin");
+    /// assert_eq!(slugify_with("しゃしん", &SlugOptions::new()), "siyasin");
+    /// assert_eq!(slugify_with("きって", &options), "kitte");
+    /// assert_eq!(slugify_with("マッチ", &options), "matchi");
+    /// assert_eq!(slugify_with("ラーメン", &options), "ramen");
+    /// assert_eq!(slugify_with("ファイル", &options), "fairu");
+    /// assert_eq!(slugify_with("ちゅうごく", &options), "chuugoku");
+    /// # }
+    /// assert_eq!(slugify_with("東京", &options), "dong-jing");
+    /// ```
+    pub fn kana_romaji(mut self, kana_romaji: bool) -> Self {
+        self.kana_romaji = kana_romaji;
+        self
+    }
+
+    /// Converts Roman numerals like `XII` to Arabic digits
+    ///
+    /// Only whole words written in uppercase which are valid numerals (`IIII` is not) are
+    /// converted, and only after a word like `Chapter`, `Part`, `Vol` or `Season`. Elsewhere
+    /// uppercase words like `MIX`, `CD` or the pronoun `I` are far more common than numerals,
+    /// so they are kept, even at the end of a title like `Rocky II`.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().roman_numerals(true);
+    /// assert_eq!(slugify_with("Chapter XII", &options), "chapter-12");
+    /// assert_eq!(slugify_with("Part IV, Vol. I", &options), "part-4-vol-1");
+    /// assert_eq!(slugify_with("I love it", &options), "i-love-it");
+    /// assert_eq!(slugify_with("MIX CD DC Chapter XII", &options), "mix-cd-dc-chapter-12");
+    /// assert_eq!(slugify_with("Rocky II", &options), "rocky-ii");
+    /// assert_eq!(slugify_with("civic XIIth IIII", &options), "civic-xiith-iiii");
+    /// assert_eq!(slugify_with("Chapter XII", &SlugOptions::new()), "chapter-xii");
+    /// ```
+    pub fn roman_numerals(mut self, roman_numerals: bool) -> Self {
+        self.roman_numerals = roman_numerals;
+        self
+    }
+
+    /// Whether a leading list marker, a number followed by `.` or `)` or one of `-`, `*` and
+    /// `•`, is removed before slugifying, for anchors of Markdown list items
+    ///
+    /// The marker must be followed by whitespace, so `1.5 liters` is not changed. Nested
+    /// markers like `1. - ` are removed as well.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().strip_list_prefix(true);
+    /// assert_eq!(slugify_with("1. Introduction", &options), "introduction");
+    /// assert_eq!(slugify_with("  12) Methods", &options), "methods");
+    /// assert_eq!(slugify_with("- Overview", &options), "overview");
+    /// assert_eq!(slugify_with("• Tea\t", &options), "tea");
+    /// assert_eq!(slugify_with("1. * Nested", &options), "nested");
+    /// assert_eq!(slugify_with("1.5 liters", &options), "1-5-liters");
+    /// assert_eq!(slugify_with("1. Introduction", &SlugOptions::new()), "1-introduction");
+    /// ```
+    pub fn strip_list_prefix(mut self, strip_list_prefix: bool) -> Self {
+        self.strip_list_prefix = strip_list_prefix;
+        self
+    }
+
+    /// File extensions removed from the end before slugifying, like `pdf` from `report.pdf`
+    ///
+    /// Extensions are compared case-insensitively and removed as long as one matches, so
+    /// `tar.gz` goes away with `["gz", "tar"]` as well as with `["tar.gz"]`. Punctuation after
+    /// a removed extension is removed too, a name which is only an extension stays as it is.
+    ///
+    /// ```rust
+    /// use slugmin::{slugify_with, SlugOptions};
+    ///
+    /// let options = SlugOptions::new().strip_extensions(&["gz", "tar"]);
+    /// assert_eq!(slugify_with("archive.tar.gz", &options), "archive");
+    /// assert_eq!(slugify_with("archive.tar.gz", &SlugOptions::new()), "archive-tar-gz");
+    /// assert_eq!(slugify_with("backup.gz.zip", &options), "backup-gz-zip");
+    /// assert_eq!(slugify_with(".gz", &options), "gz");
+    ///
+    /// let options = SlugOptions::normal(true).strip_extensions(&["pdf"]);
+    /// assert_eq!(slugify_with("report.final.PDF", &options), "report.final");
+    /// assert_eq!(slugify_with("Report.pdf ", &options), "Report");
+    /// let options = SlugOptions::new().strip_extensions(&[".pdf"]);
+    /// assert_eq!(slugify_with("report.final.PDF", &options), "report-final");
+    /// ```
+    pub fn strip_extensions(mut self, extensions: &[&str]) -> Self {
+        self.strip_extensions = extensions
+            .iter()
+            .map(|extension| extension.trim_start_matches('.').to_string())
+            .collect();
+        self
+    }
+
+    // The word and the punctuation separator
+    fn separators(&self) -> [u8; 2] {
+        [
+            self.word_separator,
+            self.punct_separator.unwrap_or(self.word_separator),
+        ]
+    }
+
+    // Bytes between the words of a slug. `.` and `_` only separate words in the normal mode,
+    // otherwise they are kept inside values like `3.14` or `10.0.0.1`
+    fn is_word_break(&self, b: u8) -> bool {
+        b == b'-'
+            || self.separators().contains(&b)
+            || (self.normal && matches!(b, b' ' | b'_' | b'.'))
+    }
+}
+
+/// Convert any unicode string to an ascii "slug", configured by `options`
+///
+/// ```rust
+/// use slugmin::{slugify_with, SlugOptions};
+///
+/// assert_eq!(slugify_with("Æúű--cool?", &SlugOptions::new()), "aeuu-cool");
+/// assert_eq!(slugify_with("roman.  txt", &SlugOptions::normal(true)), "roman. txt");
+/// ```
+pub fn slugify_with<S: AsRef<str>>(s: S, options: &SlugOptions) -> String {
+    _slugify_with(s.as_ref(), options)
+}
+
+// avoid unnecessary monomorphizations
+pub(crate) fn _slugify_with(s: &str, options: &SlugOptions) -> String {
+    _slugify_with_fallback(s, options, None)
+}
+
+// Characters without transliteration are passed to `fallback` before the unmappable strategy
+pub(crate) type Fallback<'a> = &'a dyn Fn(char) -> Option<String>;
+
+pub(crate) fn _slugify_with_fallback(
+    s: &str,
+    options: &SlugOptions,
+    fallback: Option<Fallback>,
+) -> String {
+    let question_marker = options
+        .mark_questions
+        .as_deref()
+        .filter(|_| s.trim_end().ends_with('?'));
+    let s = preprocess(s, options);
+    let s = s.as_ref();
+    let mut scanner = Scanner::new(options, s.len());
+    scanner.fallback = fallback;
+    let mut i = 0;
+    while let Some(c) = s[i..].chars().next() {
+        match scanner.push_context(s, i, c) {
+            0 => {
+                scanner.push_char(c);
+                i += c.len_utf8();
+            }
+            consumed => i += consumed,
+        }
+    }
+    if let Some(marker) = question_marker {
+        scanner.push_question_marker(marker);
+    }
+    scanner.finish()
+}
+
+// Transformations of the whole input made before the scan
+fn preprocess<'s>(s: &'s str, options: &SlugOptions) -> Cow<'s, str> {
+    let s = if options.strip_list_prefix { strip_list_prefix(s) } else { s };
+    let mut s = Cow::Borrowed(s);
+    // Elided first so that they don't split words, ICU would turn the soft hyphen into `-`.
+    // Without ICU everything which vanishes from the slug goes, like the mark in `.\u{200F}txt`
+    let elided = if options.icu_transliteration { is_elided_format } else { vanishes };
+    let transforms = options.icu_transliteration || !options.strip_extensions.is_empty();
+    if transforms && s.contains(elided) {
+        s = Cow::Owned(s.chars().filter(|&c| !elided(c)).collect());
+    }
+    // The stem is always a prefix of `s`
+    let stem_len = strip_extensions(&s, &options.strip_extensions).len();
+    match &mut s {
+        Cow::Borrowed(borrowed) => *borrowed = &borrowed[..stem_len],
+        Cow::Owned(owned) => owned.truncate(stem_len),
+    }
+    if options.roman_numerals {
+        let replaced = match replace_roman_numerals(&s) {
+            Cow::Owned(replaced) => Some(replaced),
+            Cow::Borrowed(_) => None,
+        };
+        if let Some(replaced) = replaced {
+            s = Cow::Owned(replaced);
+        }
+    }
+    if options.kana_romaji && !s.is_ascii() {
+        // Without the `kana` feature deunicode transliterates kana in the scan
+        #[cfg(feature = "kana")]
+        {
+            s = Cow::Owned(crate::kana::romanize(&s));
+        }
+    }
+    if options.icu_transliteration && !s.is_ascii() {
+        // Without the `icu` feature deunicode transliterates everything in the scan
+        #[cfg(feature = "icu")]
+        {
+            s = Cow::Owned(crate::icu::transliterate(&s));
+        }
+    }
+    s
+}
+
+// `s` without the leading list markers
+fn strip_list_prefix(mut s: &str) -> &str {
+    loop {
+        let trimmed = s.trim_start();
+        let digits = trimmed.bytes().take_while(u8::is_ascii_digit).count();
+        let marker = if digits > 0 {
+            trimmed[digits..].strip_prefix(['.', ')'])
+        } else {
+            trimmed.strip_prefix(['-', '*', '•'])
+        };
+        match marker {
+            Some(rest) if rest.starts_with(char::is_whitespace) => s = rest,
+            _ => return s,
+        }
+    }
+}
+
+// `s` without the trailing `extensions`, see `SlugOptions::strip_extensions`
+fn strip_extensions<'s>(s: &'s str, extensions: &[String]) -> &'s str {
+    if extensions.is_empty() {
+        return s;
+    }
+    let trim = |s: &'s str| s.trim_end_matches(|c: char| !c.is_alphanumeric());
+    let mut stem = trim(s);
+    let mut stripped = false;
+    loop {
+        let shorter = extensions.iter().find_map(|extension| {
+            let dot = stem.len().checked_sub(extension.len() + 1)?;
+            let suffix = stem.get(dot..)?;
+            let matches = suffix.starts_with('.') && suffix[1..].eq_ignore_ascii_case(extension);
+            Some(trim(&stem[..dot])).filter(|shorter| !shorter.is_empty() && matches)
+        });
+        match shorter {
+            Some(shorter) => {
+                stem = shorter;
+                stripped = true;
+            }
+            None if stripped => return stem,
+            None => return s,
+        }
+    }
+}
+
+// Length of the IPv4 or IPv6 address which starts at byte `i`, 0 if there is none, and the
+// IPv6 address to write in its encoded form
+fn ip_address_at(s: &str, i: usize) -> (usize, Option<Ipv6Addr>) {
+    if s[..i]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_alphanumeric() || c == ':' || c == '.')
+    {
+        return (0, None);
+    }
+    let rest = &s[i..];
+    let candidate_len = |allowed: fn(&u8) -> bool| rest.bytes().take_while(allowed).count();
+    let is_complete = |len: usize| {
+        !rest[len..]
+            .chars()
+            .next()
+            .is_some_and(char::is_alphanumeric)
+    };
+
+    let v4_len = candidate_len(|b| b.is_ascii_digit() || *b == b'.');
+    let v4 = rest[..v4_len].trim_end_matches('.');
+    if v4.parse::<Ipv4Addr>().is_ok() && is_complete(v4.len()) {
+        return (v4.len(), None);
+    }
+    let v6_len = candidate_len(|b| b.is_ascii_hexdigit() || *b == b':' || *b == b'.');
+    let v6 = rest[..v6_len].trim_end_matches('.');
+    // Words like `a::b` or a lone `::` are not taken for addresses
+    let plausible = v6.bytes().any(|b| b.is_ascii_digit())
+        && !v6.starts_with(':')
+        && !v6.ends_with(':')
+        && is_complete(v6.len());
+    let address = if v6.contains(':') {
+        v6.parse::<Ipv6Addr>().ok()
+    } else {
+        parse_encoded_ipv6(v6)
+    };
+    match address {
+        Some(address) if plausible => (v6.len(), Some(address)),
+        _ => (0, None),
+    }
+}
+
+// Length of the IPv6 address encoded by `push_ipv6_address`
+const ENCODED_IPV6_LEN: usize = 8 * 4 + 7;
+
+// Parses the 8 groups of 4 hex digits separated by `.` written by `push_ipv6_address`
+fn parse_encoded_ipv6(s: &str) -> Option<Ipv6Addr> {
+    if s.len() != ENCODED_IPV6_LEN {
+        return None;
+    }
+    let mut segments = [0u16; 8];
+    let mut groups = s.split('.');
+    for segment in segments.iter_mut() {
+        let group = groups.next().filter(|group| group.len() == 4)?;
+        if !group.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        *segment = u16::from_str_radix(group, 16).ok()?;
+    }
+    Some(Ipv6Addr::from(segments))
+}
+
+// Number of hex digits of the color code whose `#` is at byte `i`, 0 if there is none
+fn hex_color_len(bytes: &[u8], i: usize) -> usize {
+    let digits = bytes[i + 1..]
+        .iter()
+        .take_while(|b| b.is_ascii_alphanumeric())
+        .count();
+    let is_hex = bytes[i + 1..i + 1 + digits].iter().all(u8::is_ascii_hexdigit);
+    if is_hex && (digits == 3 || digits == 6) {
+        digits
+    } else {
+        0
+    }
+}
+
+// Whether the byte `i` has an ascii digit on both sides
+fn is_between_digits(bytes: &[u8], i: usize) -> bool {
+    i > 0 && bytes[i - 1].is_ascii_digit() && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+}
+
+// Whether the `decimal` at byte `i` is the only decimal point of a number
+fn is_decimal_point(bytes: &[u8], i: usize, decimal: u8, grouping: Option<u8>) -> bool {
+    let is_number_byte = |b: &u8| b.is_ascii_digit() || *b == decimal || Some(*b) == grouping;
+    if !is_between_digits(bytes, i) {
+        return false;
+    }
+    let before = bytes[..i].iter().rev().take_while(|b| is_number_byte(b));
+    let after = bytes[i + 1..].iter().take_while(|b| is_number_byte(b));
+    !before.chain(after).any(|&b| b == decimal)
+}
+
+// Whether the byte `i` separates a group of exactly three digits of a number
+fn is_grouping_separator(bytes: &[u8], i: usize) -> bool {
+    let digits = bytes[i + 1..].iter().take_while(|b| b.is_ascii_digit()).count();
+    i > 0 && bytes[i - 1].is_ascii_digit() && digits == 3
+}
+
+// Byte `cx` of the transliteration of `c` in the case of `c`, deunicode writes `ŋ` as `NG`
+// and `Ŋ` as `ng`. Transliterations of lowercase letters are lowercase, of uppercase letters
+// start with an uppercase letter and title case letters like `ǅ` are left as they are.
+fn source_case(c: char, cx: u8, first_letter: bool) -> u8 {
+    if c.is_lowercase() {
+        cx.to_ascii_lowercase()
+    } else if c.is_uppercase() && first_letter {
+        cx.to_ascii_uppercase()
+    } else {
+        cx
+    }
+}
+
+struct Scanner<'a> {
+    options: &'a SlugOptions,
+    slug: Vec<u8>,
+    prev_is_dash: bool,
+    // Normal mode runs, see `SlugOptions::max_dots`: whether the last pushed character is a
+    // space or underscore, and the number of dots pushed since the run of dots started
+    empty_space_was: bool,
+    dots_in_run: usize,
+    // Set while the `Unmappable::Replace` string is pushed, to not recurse into it
+    replacing: bool,
+    // Trailing space of a transliteration like `Dong `, only emitted before a kept character
+    pending_space: bool,
+    fallback: Option<Fallback<'a>>,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(options: &'a SlugOptions, capacity: usize) -> Self {
+        // Custom trim characters are removed in `finish`, so nothing is suppressed here
+        let default_trim = options.trim_chars.is_none();
+        Scanner {
+            options,
+            slug: Vec::with_capacity(capacity),
+            // Starts with true to avoid leading -
+            prev_is_dash: default_trim,
+            empty_space_was: default_trim,
+            dots_in_run: 0,
+            replacing: false,
+            pending_space: false,
+            fallback: None,
+        }
+    }
+
+    // Handles characters whose meaning depends on their neighbours, returns the number of
+    // consumed bytes or 0 when `c` should be pushed as usual
+    fn push_context(&mut self, s: &str, i: usize, c: char) -> usize {
+        let options = self.options;
+        let decimal = options.decimal_separator.unwrap_or(b'.');
+        if c == decimal as char
+            && (options.keep_decimals || options.decimal_separator.is_some())
+            && is_decimal_point(s.as_bytes(), i, decimal, options.grouping_separator)
+        {
+            if options.keep_decimals {
+                self.push_kept(decimal);
+            }
+            return 1;
+        }
+        if c.is_ascii()
+            && options.grouping_separator == Some(c as u8)
+            && is_grouping_separator(s.as_bytes(), i)
+        {
+            return 1;
+        }
+        if let Some(replacement) = self.options.colon_in_time {
+            // The replacement itself is kept too, so that the output is a fixed point
+            let is_colon = c == ':' || c == replacement as char;
+            if is_colon && is_between_digits(s.as_bytes(), i) {
+                if replacement == b'-' {
+                    self.push_separator(b'-');
+                } else {
+                    self.push_kept(replacement);
+                }
+                return 1;
+            }
+        }
+        if c == '·' && options.elide_middle_dot {
+            let is_letter = |c: Option<char>| c.is_some_and(char::is_alphabetic);
+            if is_letter(s[..i].chars().next_back()) && is_letter(s[i + c.len_utf8()..].chars().next()) {
+                return c.len_utf8();
+            }
+        }
+        // Joiners and variation selectors only continue a run
+        let starts_emoji_run = is_emoji(c) && !matches!(c, '\u{200D}' | '\u{FE0F}');
+        if options.emoji != EmojiMode::Transliterate && starts_emoji_run {
+            let len = s[i..].chars().take_while(|&c| is_emoji(c)).map(char::len_utf8).sum();
+            match &options.emoji {
+                EmojiMode::Placeholder(placeholder) => self.push_word(placeholder),
+                _ => self.push_word(""),
+            }
+            return len;
+        }
+        if c == '#' && options.keep_hex_colors {
+            let len = hex_color_len(s.as_bytes(), i);
+            if len > 0 {
+                // A word of its own, the `#` is a word break
+                self.push_byte(b' ');
+                for &b in &s.as_bytes()[i + 1..i + 1 + len] {
+                    self.push_byte(b);
+                }
+                return 1 + len;
+            }
+        }
+        if self.options.keep_ip_addresses && c.is_ascii_hexdigit() {
+            let (len, v6) = ip_address_at(s, i);
+            match v6 {
+                Some(address) => self.push_ipv6_address(address),
+                None => {
+                    for &b in &s.as_bytes()[i..i + len] {
+                        self.push_kept(b);
+                    }
+                }
+            }
+            return len;
+        }
+        0
+    }
+
+    fn push_char(&mut self, c: char) {
+        let options = self.options;
+        let user_word = options.overrides.iter().rev().find(|(o, _)| *o == c);
+        let word = match user_word {
+            Some((_, word)) => Some(word.as_str()),
+            None if c == '@' => match &options.at_sign {
+                AtSign::Dash => None,
+                AtSign::Drop => return,
+                AtSign::Word(word) => Some(word.as_str()),
+            },
+            None if c.is_ascii() => None,
+            None => symbol_word(c),
+        };
+        match word {
+            Some(word) => self.push_word(word),
+            None => self.push_mapped(c),
+        }
+    }
+
+    fn push_question_marker(&mut self, marker: &str) {
+        // The separators of the trailing `?` are replaced by the one before the marker
+        let separators = self.options.separators();
+        while self
+            .slug
+            .last()
+            .is_some_and(|&b| b == b'-' || b == b' ' || separators.contains(&b))
+        {
+            self.slug.pop();
+        }
+        if self.slug.is_empty() {
+            return;
+        }
+        self.prev_is_dash = false;
+        self.dots_in_run = 0;
+        self.empty_space_was = false;
+        self.push_word(marker);
+    }
+
+    fn push_word(&mut self, word: &str) {
+        // The word is pushed between `pending_space`s, which become word breaks
+        self.pending_space = true;
+        for wc in word.chars() {
+            self.push_mapped(wc);
+        }
+        self.pending_space = true;
+    }
+
+    // The ascii letter of the latin `c` without its marks, for `LatinStrategy::StripMarks`
+    fn stripped_latin(&self, c: char) -> Option<u8> {
+        match self.options.latin_strategy {
+            LatinStrategy::Deunicode => None,
+            LatinStrategy::StripMarks => {
+                strip_marks(c).filter(|_| letter_script(c) == Some(Script::Latin))
+            }
+        }
+    }
+
+    // Pushes `c` without looking at the overrides
+    fn push_mapped(&mut self, c: char) {
+        if c.is_ascii() {
+            self.push_byte(c as u8);
+        } else if self.options.unify_separators.contains(&c) || is_list_separator(c) || is_dash(c) {
+            self.push_word_separator();
+        } else if let Some(base) = self.stripped_latin(c) {
+            self.push_byte(base);
+        } else {
+            let measurement = measurement_symbol(c).filter(|_| self.options.measurement_symbols);
+            match measurement.or_else(|| transliterate(c)) {
+                Some(ascii) => {
+                    let word = ascii.trim_end_matches(' ');
+                    let mut first_letter = true;
+                    for &cx in word.as_bytes() {
+                        if self.options.leave_size {
+                            self.push_byte(source_case(c, cx, first_letter));
+                            first_letter &= !cx.is_ascii_alphabetic();
+                        } else {
+                            self.push_byte(cx);
+                        }
+                    }
+                    // A following separator or dot replaces the space, so `東!x` is not `dong -x`
+                    self.pending_space |= word.len() != ascii.len();
+                }
+                None => match self.fallback.and_then(|fallback| fallback(c)) {
+                    Some(replacement) => {
+                        // The fallback is not used for its own output
+                        let fallback = self.fallback.take();
+                        for rc in replacement.chars() {
+                            self.push_char(rc);
+                        }
+                        self.fallback = fallback;
+                    }
+                    None => self.push_unmappable(c),
+                },
+            }
+        }
+    }
+
+    fn push_byte(&mut self, x: u8) {
+        if self.options.unify_separators.contains(&(x as char)) {
+            self.push_word_separator();
+            return;
+        }
+        if self.options.internal_chars.contains(&x) {
+            let internal_chars = &self.options.internal_chars;
+            let in_word = self.slug.last().is_some_and(|b| {
+                b.is_ascii_alphanumeric() || internal_chars.contains(b)
+            });
+            if in_word {
+                // Attached to the word, not to the transliteration's trailing space
+                self.pending_space = false;
+                self.push_kept(x);
+            } else {
+                self.push_word_separator();
+            }
+            return;
+        }
+        let options = self.options;
+        // Separators in the input stay the same kind of break, except the characters kept in
+        // normal mode
+        if !(options.normal && matches!(x, b'_' | b'.')) {
+            let [word_separator, punct_separator] = options.separators();
+            if x == punct_separator && x != word_separator {
+                self.push_separator(x);
+                return;
+            }
+            if x == word_separator {
+                self.push_word_separator();
+                return;
+            }
+        }
+        let not_in_alphabet = self.options.output_alphabet.as_ref().is_some_and(|alphabet| {
+            !alphabet.iter().any(|b| b.eq_ignore_ascii_case(&x))
+        });
+        match x {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' if not_in_alphabet => {}
+            b'a'..=b'z' => self.push_letter(x),
+            b'0'..=b'9' => self.push_kept(x),
+            b'A'..=b'Z' => {
+                if self.options.leave_size {
+                    self.push_letter(x);
+                } else {
+                    // Manual lowercasing as Rust to_lowercase() is unicode
+                    // aware and therefore much slower
+                    self.push_letter(x - b'A' + b'a');
+                }
+            }
+            b' ' | b'_' if self.options.normal => {
+                self.pending_space = false;
+                let removed = self.options.collapse_dot_space && self.dots_in_run > 0;
+                if !self.empty_space_was && !removed {
+                    self.slug.push(x);
+                    self.prev_is_dash = false;
+                    self.dots_in_run = 0;
+                    self.empty_space_was = true;
+                }
+            }
+            b'.' if self.options.normal => {
+                self.pending_space = false;
+                if self.dots_in_run < self.options.max_dots {
+                    self.slug.push(x);
+                    self.prev_is_dash = false;
+                    self.dots_in_run += 1;
+                    self.empty_space_was = false;
+                }
+            }
+            b'_' if self.options.keep_underscore => {
+                // Attached to the word, not to the transliteration's trailing space
+                self.pending_space = false;
+                if !self.slug.is_empty() {
+                    self.push_kept(x);
+                }
+            }
+            b'-' | b'_' => self.push_word_separator(),
+            _ if x.is_ascii_whitespace() => self.push_word_separator(),
+            _ => self.push_unmappable(x as char),
+        }
+    }
+
+    fn push_unmappable(&mut self, c: char) {
+        let options = self.options;
+        match &options.unmappable {
+            Unmappable::Dash => self.push_separator(options.separators()[1]),
+            Unmappable::Delete => {}
+            Unmappable::Replace(_) if self.replacing => self.push_separator(options.separators()[1]),
+            Unmappable::Replace(replacement) => {
+                self.replacing = true;
+                for rc in replacement.chars() {
+                    self.push_char(rc);
+                }
+                self.replacing = false;
+            }
+            Unmappable::PercentEncode => percent_encode(c, |b| self.push_kept(b)),
+        }
+    }
+
+    fn push_letter(&mut self, x: u8) {
+        if self.options.dedupe_repeats && !self.pending_space {
+            let repeated = self.slug.len() >= 2
+                && self.slug[self.slug.len() - 2..]
+                    .iter()
+                    .all(|b| b.eq_ignore_ascii_case(&x));
+            if repeated {
+                return;
+            }
+        }
+        self.push_kept(x);
+    }
+
+    // Writes all 8 groups of 4 hex digits, separated by `.` instead of `:`
+    fn push_ipv6_address(&mut self, address: Ipv6Addr) {
+        for (i, segment) in address.segments().iter().enumerate() {
+            if i > 0 {
+                self.push_kept(b'.');
+            }
+            for b in format!("{:04x}", segment).bytes() {
+                self.push_kept(b);
+            }
+        }
+    }
+
+    fn push_kept(&mut self, x: u8) {
+        if self.pending_space {
+            self.pending_space = false;
+            self.push_byte(b' ');
+        }
+        self.prev_is_dash = false;
+        self.dots_in_run = 0;
+        self.empty_space_was = false;
+        self.slug.push(x);
+    }
+
+    fn push_word_separator(&mut self) {
+        self.push_separator(self.options.word_separator);
+    }
+
+    fn push_separator(&mut self, separator: u8) {
+        self.pending_space = false;
+        // Without collapsing only the leading separators are suppressed
+        let uncollapsed = !self.options.collapse_separators && !self.slug.is_empty();
+        if !self.prev_is_dash || uncollapsed {
+            self.slug.push(separator);
+            self.prev_is_dash = true;
+            self.dots_in_run = 0;
+            self.empty_space_was = false;
+        } else if separator == self.options.separators()[1] {
+            // Punctuation wins in a collapsed run of both separators
+            if let Some(last) = self.slug.last_mut() {
+                if *last == self.options.word_separator {
+                    *last = separator;
+                }
+            }
+        }
+    }
+
+    fn finish(self) -> String {
+        // The scanner only pushes ascii bytes, checked in debug builds
+        debug_assert!(self.slug.is_ascii(), "slug is not ascii: {:?}", self.slug);
+        let mut string = unsafe { String::from_utf8_unchecked(self.slug) };
+        if self.options.dedupe_words {
+            string = dedupe_words(&string, self.options);
+        }
+        if let Some(len) = self.options.abbreviate_words {
+            string = abbreviate_words(&string, len, self.options);
+        }
+        match &self.options.trim_chars {
+            Some(trim_chars) => {
+                let trimmed = string.trim_matches(|c| trim_chars.contains(&c));
+                if trimmed.len() != string.len() {
+                    string = trimmed.to_string();
+                }
+            }
+            None => {
+                // Removes from the end `-`, ` `, the separators and the kept `_`
+                let separators = self.options.separators();
+                let underscore = self.options.keep_underscore && !self.options.normal;
+                while string.bytes().next_back().is_some_and(|b| {
+                    b == b'-' || b == b' ' || separators.contains(&b) || (underscore && b == b'_')
+                }) {
+                    string.pop();
+                }
+            }
+        }
+        if string.starts_with(|c: char| c.is_ascii_digit()) {
+            match self.options.leading_digit {
+                LeadingDigit::Allow => {}
+                LeadingDigit::Strip => {
+                    let start = string.find(|c: char| c.is_ascii_alphabetic());
+                    string.drain(..start.unwrap_or(string.len()));
+                }
+                LeadingDigit::Prefix(c) => string.insert(0, c),
+            }
+        }
+        if let Some(max) = self.options.max_separators {
+            string = join_words_after(&string, max, self.options);
+        }
+        if let Some(max_length) = self.options.max_length {
+            truncate_words(&mut string, max_length, self.options);
+            if self.options.unmappable == Unmappable::PercentEncode {
+                trim_cut_escape(&mut string);
+            }
+        }
+        // We likely reserved more space than needed.
+        string.shrink_to_fit();
+        string
+    }
+}
+
+// Removes the percent encoded character which `truncate_words` cut in half, if any
+fn trim_cut_escape(slug: &mut String) {
+    let tail = slug.len().saturating_sub(2);
+    if let Some(i) = slug[tail..].rfind('%') {
+        slug.truncate(tail + i);
+    }
+    // The UTF-8 continuation bytes at the end must complete the character
+    let mut end = slug.len();
+    let mut continuation_bytes = 0;
+    while end >= 3 && slug.as_bytes()[end - 3] == b'%' {
+        match u8::from_str_radix(&slug[end - 2..end], 16) {
+            Ok(0x80..=0xBF) => continuation_bytes += 1,
+            Ok(lead @ 0xC0..=0xFF) => {
+                if lead.leading_ones() as usize != continuation_bytes + 1 {
+                    slug.truncate(end - 3);
+                }
+                return;
+            }
+            _ => return,
+        }
+        end -= 3;
+    }
+}
+
+// Removes every word equal to the word before it, together with the separators before it
+fn dedupe_words(slug: &str, options: &SlugOptions) -> String {
+    let bytes = slug.as_bytes();
+    let mut deduped = String::with_capacity(slug.len());
+    let mut prev_word: Option<&str> = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        let word_start = bytes[i..]
+            .iter()
+            .position(|&b| !options.is_word_break(b))
+            .map_or(bytes.len(), |offset| i + offset);
+        let word_end = bytes[word_start..]
+            .iter()
+            .position(|&b| options.is_word_break(b))
+            .map_or(bytes.len(), |offset| word_start + offset);
+        let word = &slug[word_start..word_end];
+        let repeated =
+            !word.is_empty() && prev_word.is_some_and(|prev| prev.eq_ignore_ascii_case(word));
+        if !repeated {
+            deduped.push_str(&slug[i..word_end]);
+            prev_word = Some(word);
+        }
+        i = word_end;
+    }
+    deduped
+}
+
+// Cuts every word except numbers, also kept ones like `3.14`, to at most `len` bytes
+fn abbreviate_words(slug: &str, len: usize, options: &SlugOptions) -> String {
+    let mut abbreviated = String::with_capacity(slug.len());
+    let mut rest = slug;
+    while !rest.is_empty() {
+        let (word, tail) = split_run(rest, false, options);
+        let (separator, tail) = split_run(tail, true, options);
+        if word.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+            abbreviated.push_str(word);
+        } else {
+            // Slugs are ascii, so a word can be cut anywhere
+            abbreviated.push_str(&word[..word.len().min(len)]);
+        }
+        abbreviated.push_str(separator);
+        rest = tail;
+    }
+    abbreviated
+}
+
+// Removes the runs of separators after the first `max` ones, joining the words around them.
+// Words with a `.`, which is only kept in numbers and addresses, are not joined
+fn join_words_after(slug: &str, max: usize, options: &SlugOptions) -> String {
+    let mut joined = String::with_capacity(slug.len());
+    let mut kept = 0;
+    let mut rest = slug;
+    while !rest.is_empty() {
+        let (word, tail) = split_run(rest, false, options);
+        let (separator, tail) = split_run(tail, true, options);
+        let next_word = split_run(tail, false, options).0;
+        joined.push_str(word);
+        if separator.is_empty() {
+            // The end of the slug
+        } else if word.contains('.') || next_word.contains('.') {
+            joined.push_str(separator);
+        } else if kept < max {
+            joined.push_str(separator);
+            kept += 1;
+        }
+        rest = tail;
+    }
+    joined
+}
+
+// Splits `s` after the leading run of separators, or of word bytes
+fn split_run<'a>(s: &'a str, separators_run: bool, options: &SlugOptions) -> (&'a str, &'a str) {
+    let end = s
+        .bytes()
+        .position(|b| options.is_word_break(b) != separators_run);
+    s.split_at(end.unwrap_or(s.len()))
+}
+
+// Cuts `slug` to at most `max_length` bytes at a word boundary
+fn truncate_words(slug: &mut String, max_length: usize, options: &SlugOptions) {
+    let is_separator = |b: u8| options.is_word_break(b);
+    // Like the separators, a kept `_` is removed from the end
+    let underscore = options.keep_underscore && !options.normal;
+    let is_trailing = |b: u8| is_separator(b) || (underscore && b == b'_');
+    if slug.len() <= max_length {
+        return;
+    }
+    let bytes = slug.as_bytes();
+    let mut end = max_length;
+    if !is_separator(bytes[end]) {
+        // Drops the partial word, unless it is the only one
+        if let Some(start) = bytes[..end].iter().rposition(|&b| is_separator(b)) {
+            end = start;
+        }
+    }
+    loop {
+        end = bytes[..end].iter().rposition(|&b| !is_trailing(b)).map_or(0, |i| i + 1);
+        let word_start = bytes[..end].iter().rposition(|&b| is_separator(b));
+        match word_start {
+            Some(start) if end - start - 1 < options.min_last_word_len => end = start,
+            // A word followed the number, as the slug was cut
+            Some(start)
+                if options.keep_trailing_number
+                    && bytes[start + 1..end].iter().all(u8::is_ascii_digit) =>
+            {
+                end = start
+            }
+            _ => break,
+        }
+    }
+    slug.truncate(end);
+}
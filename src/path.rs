@@ -0,0 +1,110 @@
+use crate::slugify;
+
+/// Slugify only the last segment of an URL or path, everything before it is kept unchanged
+///
+/// A trailing `/` is kept and the segment before it is slugified. Input without a `/` is
+/// slugified as a whole.
+///
+/// ```rust
+/// use slugmin::slugify_last_segment;
+///
+/// assert_eq!(slugify_last_segment("/blog/My Post!"), "/blog/my-post");
+/// assert_eq!(slugify_last_segment("https://example.com/Blog/My Post/"), "https://example.com/Blog/my-post/");
+/// assert_eq!(slugify_last_segment("My Post"), "my-post");
+/// assert_eq!(slugify_last_segment("/"), "/");
+/// ```
+pub fn slugify_last_segment<S: AsRef<str>>(s: S) -> String {
+    _slugify_last_segment(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_last_segment(s: &str) -> String {
+    let (path, trailing) = match s.strip_suffix('/') {
+        Some(path) => (path, "/"),
+        None => (s, ""),
+    };
+    let (prefix, segment) = match path.rfind('/') {
+        Some(i) => path.split_at(i + 1),
+        None => ("", path),
+    };
+    let mut result = String::with_capacity(s.len());
+    result.push_str(prefix);
+    result.push_str(&slugify(segment));
+    result.push_str(trailing);
+    result
+}
+
+/// What `slugify_path_with` does with a segment whose slug is empty
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum EmptySegment {
+    /// Remove the segment, so `a//b` becomes `a/b` (the default)
+    #[default]
+    Drop,
+    /// Write this string instead, which keeps the number of segments
+    Placeholder(String),
+}
+
+/// Slugify every `/` separated segment of a path, removing the empty ones
+///
+/// A leading and a trailing `/` are kept. Segments like `..` have an empty slug, so they
+/// are removed as well and the path can't leave its root.
+///
+/// ```rust
+/// use slugmin::slugify_path;
+///
+/// assert_eq!(slugify_path("Blog/My Post!"), "blog/my-post");
+/// assert_eq!(slugify_path("/a//b/"), "/a/b/");
+/// assert_eq!(slugify_path("docs/../Secret Files"), "docs/secret-files");
+/// assert_eq!(slugify_path("//"), "/");
+/// ```
+pub fn slugify_path<S: AsRef<str>>(s: S) -> String {
+    _slugify_path(s.as_ref(), &EmptySegment::Drop)
+}
+
+/// Like `slugify_path`, with a choice of what happens to empty segments
+///
+/// The leading and trailing `/` are not segments, so they never get a placeholder.
+///
+/// ```rust
+/// use slugmin::{slugify_path_with, EmptySegment};
+///
+/// assert_eq!(slugify_path_with("a//b", &EmptySegment::Drop), "a/b");
+/// let placeholder = EmptySegment::Placeholder("_".to_string());
+/// assert_eq!(slugify_path_with("a//b", &placeholder), "a/_/b");
+/// assert_eq!(slugify_path_with("/a/!!!/b/", &placeholder), "/a/_/b/");
+/// assert_eq!(slugify_path_with("/", &placeholder), "/");
+/// ```
+pub fn slugify_path_with<S: AsRef<str>>(s: S, empty_segment: &EmptySegment) -> String {
+    _slugify_path(s.as_ref(), empty_segment)
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_path(s: &str, empty_segment: &EmptySegment) -> String {
+    let leading = s.starts_with('/');
+    let inner = s.strip_prefix('/').unwrap_or(s);
+    let trailing = !inner.is_empty() && inner.ends_with('/');
+    let inner = inner.strip_suffix('/').unwrap_or(inner);
+
+    let mut path = String::with_capacity(s.len());
+    if leading {
+        path.push('/');
+    }
+    let mut first = true;
+    for segment in inner.split('/').filter(|_| !inner.is_empty()) {
+        let slug = slugify(segment);
+        let slug = match empty_segment {
+            _ if !slug.is_empty() => slug.as_str(),
+            EmptySegment::Drop => continue,
+            EmptySegment::Placeholder(placeholder) => placeholder.as_str(),
+        };
+        if !first {
+            path.push('/');
+        }
+        path.push_str(slug);
+        first = false;
+    }
+    if trailing && !first {
+        path.push('/');
+    }
+    path
+}
@@ -0,0 +1,75 @@
+use crate::options::_slugify_with;
+use crate::slug::_is_slug;
+use crate::{slugify, SlugOptions};
+
+/// Named sets of rules a slug can be made for, see [`is_valid_for`](fn.is_valid_for.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Preset {
+    /// What `slugify` returns: a-z, 0-9 and single `-` which are never leading or trailing
+    Slug,
+    /// A DNS label (RFC 1123): like `Slug`, but at least 1 and at most 63 bytes
+    DnsLabel,
+    /// What `slugify_normal(s, true)` returns, which keeps case, spaces, `_` and `.`
+    Filename,
+}
+
+const DNS_LABEL_MAX_LEN: usize = 63;
+
+/// Whether `s` already follows the rules of `preset`, so it can be accepted as it is
+///
+/// ```rust
+/// use slugmin::{is_valid_for, Preset};
+///
+/// assert!(is_valid_for("my-post", Preset::DnsLabel));
+/// assert!(!is_valid_for("my_post", Preset::DnsLabel));
+/// assert!(is_valid_for("my_post", Preset::Filename));
+/// assert!(is_valid_for("My Post.txt", Preset::Filename));
+/// assert!(!is_valid_for("My  Post.txt", Preset::Filename));
+/// assert!(is_valid_for("", Preset::Slug));
+/// assert!(!is_valid_for("", Preset::DnsLabel));
+/// assert!(!is_valid_for(&"a".repeat(64), Preset::DnsLabel));
+/// ```
+pub fn is_valid_for<S: AsRef<str>>(s: S, preset: Preset) -> bool {
+    _is_valid_for(s.as_ref(), preset)
+}
+
+// avoid unnecessary monomorphizations
+fn _is_valid_for(s: &str, preset: Preset) -> bool {
+    match preset {
+        Preset::Slug => _is_slug(s),
+        Preset::DnsLabel => (1..=DNS_LABEL_MAX_LEN).contains(&s.len()) && _is_slug(s),
+        // The rules of normal mode are those of its output, which is idempotent
+        Preset::Filename => _slugify_with(s, &SlugOptions::normal(true)) == s,
+    }
+}
+
+/// Convert any unicode string to a slug following the rules of `preset`
+///
+/// Input which already follows them is returned as it is. A `DnsLabel` is cut at a word
+/// boundary to 63 bytes, it is empty when `s` has no letters or numbers, which is not a valid
+/// label.
+///
+/// ```rust
+/// use slugmin::{is_valid_for, slugify_preset, Preset};
+///
+/// assert_eq!(slugify_preset("My Post!", Preset::Slug), "my-post");
+/// assert_eq!(slugify_preset("my_post", Preset::DnsLabel), "my-post");
+/// assert_eq!(slugify_preset("My Post.txt", Preset::Filename), "My Post.txt");
+/// let label = slugify_preset("a very long title ".repeat(10), Preset::DnsLabel);
+/// assert!(label.len() <= 63 && is_valid_for(&label, Preset::DnsLabel));
+/// ```
+pub fn slugify_preset<S: AsRef<str>>(s: S, preset: Preset) -> String {
+    _slugify_preset(s.as_ref(), preset)
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_preset(s: &str, preset: Preset) -> String {
+    if _is_valid_for(s, preset) {
+        return s.to_string();
+    }
+    match preset {
+        Preset::Slug => slugify(s),
+        Preset::DnsLabel => _slugify_with(s, &SlugOptions::new().max_length(DNS_LABEL_MAX_LEN)),
+        Preset::Filename => _slugify_with(s, &SlugOptions::normal(true)),
+    }
+}
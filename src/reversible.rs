@@ -0,0 +1,132 @@
+use std::mem;
+
+/// What `slugify` removed from a title, see [`slugify_reversible`](fn.slugify_reversible.html)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SlugMeta {
+    leading: String,
+    separators: Vec<String>,
+    trailing: String,
+    uppercase: Vec<usize>,
+}
+
+impl SlugMeta {
+    /// The separators and dropped characters before the first word
+    pub fn leading(&self) -> &str {
+        &self.leading
+    }
+
+    /// The original text of every `-` of the slug, in order
+    ///
+    /// It is empty when the transliteration separated the words, like in `東京`.
+    pub fn separators(&self) -> &[String] {
+        &self.separators
+    }
+
+    /// The separators and dropped characters after the last word
+    pub fn trailing(&self) -> &str {
+        &self.trailing
+    }
+
+    /// Byte offsets in the slug of the letters which were uppercase, in increasing order
+    pub fn uppercase(&self) -> &[usize] {
+        &self.uppercase
+    }
+}
+
+/// Like `slugify`, but also returns where the separators and uppercase letters of `s` were
+///
+/// The slug is exactly `slugify(s)`. With the [`SlugMeta`](struct.SlugMeta.html) the title
+/// can be approximated again by [`reconstruct`](fn.reconstruct.html), which is exact for ascii
+/// titles. Transliterated characters are not recorded, they come back as their ascii letters.
+///
+/// ```rust
+/// use slugmin::{slugify, slugify_reversible};
+///
+/// let (slug, meta) = slugify_reversible("  Hello, World!");
+/// assert_eq!(slug, slugify("  Hello, World!"));
+/// assert_eq!(meta.leading(), "  ");
+/// assert_eq!(meta.separators(), [", "]);
+/// assert_eq!(meta.trailing(), "!");
+/// assert_eq!(meta.uppercase(), [0, 6]);
+/// ```
+pub fn slugify_reversible<S: AsRef<str>>(s: S) -> (String, SlugMeta) {
+    _slugify_reversible(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_reversible(s: &str) -> (String, SlugMeta) {
+    let mut slug = String::with_capacity(s.len());
+    let mut meta = SlugMeta::default();
+    // The original text of the separator after the last word
+    let mut gap = String::new();
+    // Starts with true like in `slug_bytes_while`, the first word is not preceded by a `-`
+    let mut in_gap = true;
+    for c in s.chars() {
+        let mut in_word = false;
+        crate::char_bytes(c, None, &mut |x| {
+            if !x.is_ascii_alphanumeric() {
+                in_gap = true;
+                return true;
+            }
+            if in_gap {
+                if slug.is_empty() {
+                    meta.leading = mem::take(&mut gap);
+                } else {
+                    slug.push('-');
+                    meta.separators.push(mem::take(&mut gap));
+                }
+                in_gap = false;
+            }
+            if x.is_ascii_uppercase() {
+                meta.uppercase.push(slug.len());
+            }
+            slug.push(x.to_ascii_lowercase() as char);
+            in_word = true;
+            true
+        });
+        // Characters dropped inside a word, like a soft hyphen, are not kept
+        if in_gap && !in_word {
+            gap.push(c);
+        }
+    }
+    meta.trailing = gap;
+    (slug, meta)
+}
+
+/// Approximate the title `slug` was made from, using the `meta` given with it by
+/// [`slugify_reversible`](fn.slugify_reversible.html)
+///
+/// The separators, the uppercase letters and the text before and after the slug are put back,
+/// a separator without original text stays a `-`.
+///
+/// ```rust
+/// use slugmin::{reconstruct, slugify_reversible};
+///
+/// let title = "  The Rust Book: Chapter 1 -- Getting Started!";
+/// let (slug, meta) = slugify_reversible(title);
+/// assert_eq!(slug, "the-rust-book-chapter-1-getting-started");
+/// assert_eq!(reconstruct(&slug, &meta), title);
+///
+/// let (slug, meta) = slugify_reversible("Ærøskøbing, 東京");
+/// assert_eq!(slug, "aeroskobing-dong-jing");
+/// assert_eq!(reconstruct(&slug, &meta), "AEroskobing, Dong-Jing");
+/// ```
+pub fn reconstruct(slug: &str, meta: &SlugMeta) -> String {
+    let mut title = String::with_capacity(slug.len() + meta.leading.len() + meta.trailing.len());
+    title.push_str(&meta.leading);
+    let mut separators = meta.separators.iter();
+    for (i, b) in slug.bytes().enumerate() {
+        if b == b'-' {
+            match separators.next() {
+                Some(separator) if !separator.is_empty() => title.push_str(separator),
+                _ => title.push('-'),
+            }
+        } else if meta.uppercase.binary_search(&i).is_ok() {
+            title.push(b.to_ascii_uppercase() as char);
+        } else {
+            title.push(b as char);
+        }
+    }
+    title.push_str(&meta.trailing);
+    title
+}
@@ -0,0 +1,94 @@
+use crate::chars::vanishes;
+use std::borrow::Cow;
+
+const NUMERALS: &[(&str, u32)] = &[
+    ("M", 1000),
+    ("CM", 900),
+    ("D", 500),
+    ("CD", 400),
+    ("C", 100),
+    ("XC", 90),
+    ("L", 50),
+    ("XL", 40),
+    ("X", 10),
+    ("IX", 9),
+    ("V", 5),
+    ("IV", 4),
+    ("I", 1),
+];
+
+// Value of a canonical uppercase Roman numeral like `XII`, so `IIII` or `VX` are not numerals
+fn roman_value(token: &str) -> Option<u32> {
+    let mut rest = token;
+    let mut value = 0;
+    for &(numeral, numeral_value) in NUMERALS {
+        while let Some(stripped) = rest.strip_prefix(numeral) {
+            rest = stripped;
+            value += numeral_value;
+        }
+    }
+    if !rest.is_empty() || value == 0 || to_roman(value) != token {
+        return None;
+    }
+    Some(value)
+}
+
+fn to_roman(mut value: u32) -> String {
+    let mut roman = String::new();
+    for &(numeral, numeral_value) in NUMERALS {
+        while value >= numeral_value {
+            roman.push_str(numeral);
+            value -= numeral_value;
+        }
+    }
+    roman
+}
+
+// Words after which a Roman numeral is expected, in lowercase
+const CONTEXT_WORDS: &[&str] = &[
+    "act", "appendix", "book", "chapter", "episode", "part", "season", "section", "vol", "volume",
+];
+
+// Replaces whole words which are Roman numerals by Arabic digits when they follow one of the
+// `CONTEXT_WORDS`
+pub(crate) fn replace_roman_numerals(s: &str) -> Cow<'_, str> {
+    // Byte range and ascii letters and digits of every word
+    let mut words = Vec::new();
+    let mut word_start = None;
+    for (i, c) in s.char_indices().chain(std::iter::once((s.len(), ' '))) {
+        // Not unicode aware, as `東XII` is `Dong XII` after the transliteration. Characters
+        // which are dropped, like `ע`, don't end the word, `IעX` is `IX` in the slug.
+        if c.is_ascii_alphanumeric() || vanishes(c) {
+            word_start.get_or_insert(i);
+            continue;
+        }
+        if let Some(start) = word_start.take() {
+            let word: String = s[start..i]
+                .chars()
+                .filter(char::is_ascii_alphanumeric)
+                .collect();
+            if !word.is_empty() {
+                words.push((start, i, word));
+            }
+        }
+    }
+    let mut result = String::new();
+    // Start of the not yet copied part of `s`
+    let mut copied = 0;
+    for (index, (start, end, word)) in words.iter().enumerate().skip(1) {
+        let previous = words[index - 1].2.to_ascii_lowercase();
+        if !CONTEXT_WORDS.contains(&previous.as_str()) {
+            continue;
+        }
+        if let Some(value) = roman_value(word) {
+            result.push_str(&s[copied..*start]);
+            result.push_str(&value.to_string());
+            copied = *end;
+        }
+    }
+    if copied == 0 {
+        return Cow::Borrowed(s);
+    }
+    result.push_str(&s[copied..]);
+    Cow::Owned(result)
+}
@@ -0,0 +1,128 @@
+use crate::chars::is_combining_mark;
+use crate::slugify;
+
+/// Writing system of the letters of a text, see
+/// [`slugify_with_script`](fn.slugify_with_script.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Hangul,
+    /// Hiragana and katakana
+    Kana,
+    /// Chinese characters, also the kanji in Japanese text and the hanja in Korean text
+    Han,
+    /// Letters of any other script
+    Other,
+    /// No script has more than half of the letters
+    Mixed,
+    /// There are no letters, only digits, punctuation or symbols
+    Unknown,
+}
+
+// Every script a single letter can be of
+const LETTER_SCRIPTS: [Script; 10] = [
+    Script::Latin,
+    Script::Greek,
+    Script::Cyrillic,
+    Script::Hebrew,
+    Script::Arabic,
+    Script::Devanagari,
+    Script::Hangul,
+    Script::Kana,
+    Script::Han,
+    Script::Other,
+];
+
+/// Like `slugify`, but also returns the script of most letters of `s`, before transliteration
+///
+/// The letters of every script are counted, the script with more than half of them is
+/// dominant. Digits, punctuation, symbols and combining marks are not counted.
+///
+/// ```rust
+/// use slugmin::{slugify_with_script, Script};
+///
+/// let (slug, script) = slugify_with_script("Привет, мир!");
+/// assert_eq!(slug, "privet-mir");
+/// assert_eq!(script, Script::Cyrillic);
+/// let (slug, script) = slugify_with_script("Rust 東京大会");
+/// assert_eq!(slug, "rust-dong-jing-da-hui");
+/// assert_eq!(script, Script::Mixed);
+/// assert_eq!(slugify_with_script("Visit 東京").1, Script::Latin);
+/// assert_eq!(slugify_with_script("Ελληνικά 2024").1, Script::Greek);
+/// assert_eq!(slugify_with_script("Ærøskøbing").1, Script::Latin);
+/// assert_eq!(slugify_with_script("カタカナ").1, Script::Kana);
+/// assert_eq!(slugify_with_script("1, 2, 3!"), ("1-2-3".to_string(), Script::Unknown));
+/// ```
+pub fn slugify_with_script<S: AsRef<str>>(s: S) -> (String, Script) {
+    _slugify_with_script(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_with_script(s: &str) -> (String, Script) {
+    let mut counts = [0usize; LETTER_SCRIPTS.len()];
+    let mut letters = 0;
+    for script in s.chars().filter_map(letter_script) {
+        // The scripts of letters are declared first, in the order of `LETTER_SCRIPTS`
+        counts[script as usize] += 1;
+        letters += 1;
+    }
+    let script = match counts.iter().enumerate().max_by_key(|&(_, &count)| count) {
+        _ if letters == 0 => Script::Unknown,
+        Some((index, &count)) if count * 2 > letters => LETTER_SCRIPTS[index],
+        _ => Script::Mixed,
+    };
+    (slugify(s), script)
+}
+
+// Script of `c` when it is a letter
+pub(crate) fn letter_script(c: char) -> Option<Script> {
+    if !c.is_alphabetic() || is_combining_mark(c) {
+        return None;
+    }
+    let script = match c {
+        'A'..='Z'
+        | 'a'..='z'
+        | '\u{AA}'
+        | '\u{BA}'
+        | '\u{C0}'..='\u{2AF}'
+        | '\u{1D00}'..='\u{1DBF}'
+        | '\u{1E00}'..='\u{1EFF}'
+        | '\u{2C60}'..='\u{2C7F}'
+        | '\u{A720}'..='\u{A7FF}'
+        | '\u{AB30}'..='\u{AB6F}'
+        | '\u{FB00}'..='\u{FB06}'
+        | '\u{FF21}'..='\u{FF3A}'
+        | '\u{FF41}'..='\u{FF5A}' => Script::Latin,
+        '\u{370}'..='\u{3FF}' | '\u{1F00}'..='\u{1FFF}' => Script::Greek,
+        '\u{400}'..='\u{52F}'
+        | '\u{1C80}'..='\u{1C8F}'
+        | '\u{2DE0}'..='\u{2DFF}'
+        | '\u{A640}'..='\u{A69F}' => Script::Cyrillic,
+        '\u{590}'..='\u{5FF}' | '\u{FB1D}'..='\u{FB4F}' => Script::Hebrew,
+        '\u{600}'..='\u{6FF}'
+        | '\u{750}'..='\u{77F}'
+        | '\u{8A0}'..='\u{8FF}'
+        | '\u{FB50}'..='\u{FDFF}'
+        | '\u{FE70}'..='\u{FEFF}' => Script::Arabic,
+        '\u{900}'..='\u{97F}' | '\u{A8E0}'..='\u{A8FF}' => Script::Devanagari,
+        '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' | '\u{AC00}'..='\u{D7FF}' => {
+            Script::Hangul
+        }
+        '\u{3040}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' | '\u{FF66}'..='\u{FF9F}' => Script::Kana,
+        '\u{2E80}'..='\u{2FDF}'
+        | '\u{3005}'
+        | '\u{3007}'
+        | '\u{3021}'..='\u{3029}'
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{20000}'..='\u{3FFFF}' => Script::Han,
+        _ => Script::Other,
+    };
+    Some(script)
+}
@@ -0,0 +1,223 @@
+use crate::slugify;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Whether `s` is a slug, exactly what `slugify` returns for it
+///
+/// Slugs only contain a-z, 0-9 and single '-' which are never leading or trailing.
+///
+/// ```rust
+/// use slugmin::is_slug;
+///
+/// assert!(is_slug("my-test-string-1"));
+/// assert!(is_slug(""));
+/// assert!(!is_slug("My-Test"));
+/// assert!(!is_slug("my--test"));
+/// assert!(!is_slug("-my-test"));
+/// assert!(!is_slug("my_test"));
+/// ```
+pub fn is_slug<S: AsRef<str>>(s: S) -> bool {
+    _is_slug(s.as_ref())
+}
+
+// Single pass, as `slugify` checks it for every input
+pub(crate) fn _is_slug(s: &str) -> bool {
+    let mut prev_is_dash = true;
+    for b in s.bytes() {
+        match b {
+            b'a'..=b'z' | b'0'..=b'9' => prev_is_dash = false,
+            b'-' if !prev_is_dash => prev_is_dash = true,
+            _ => return false,
+        }
+    }
+    !prev_is_dash || s.is_empty()
+}
+
+/// Repairs `s` to a slug and tells whether it already was one, so `slugify(s) == s`
+///
+/// ```rust
+/// use slugmin::slugify_validate;
+///
+/// assert_eq!(slugify_validate("my-test-string"), ("my-test-string".to_string(), true));
+/// assert_eq!(slugify_validate("My Test String!"), ("my-test-string".to_string(), false));
+/// assert_eq!(slugify_validate(""), ("".to_string(), true));
+/// ```
+pub fn slugify_validate<S: AsRef<str>>(s: S) -> (String, bool) {
+    let s = s.as_ref();
+    // `slugify` output is always a slug, so this is the same as comparing with it
+    if _is_slug(s) {
+        (s.to_string(), true)
+    } else {
+        (slugify(s), false)
+    }
+}
+
+/// String which is guaranteed to be a slug
+///
+/// ```rust
+/// use slugmin::Slug;
+///
+/// assert_eq!(Slug::new("My Test String!").as_str(), "my-test-string");
+/// assert_eq!("my-test-string".parse::<Slug>().unwrap(), Slug::new("My Test String"));
+/// assert!("My Test String".parse::<Slug>().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Slug(String);
+
+impl Slug {
+    /// Slugifies `s`
+    pub fn new<S: AsRef<str>>(s: S) -> Self {
+        Slug(slugify(s))
+    }
+
+    /// Accepts `s` only when it is already a slug
+    pub fn parse<S: AsRef<str>>(s: S) -> Result<Self, InvalidSlug> {
+        let s = s.as_ref();
+        if is_slug(s) {
+            Ok(Slug(s.to_string()))
+        } else {
+            Err(InvalidSlug {
+                input: s.to_string(),
+            })
+        }
+    }
+
+    /// The slug as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The slug as a `String`, without copying it
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl FromStr for Slug {
+    type Err = InvalidSlug;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Slug::parse(s)
+    }
+}
+
+impl AsRef<str> for Slug {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Slug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<Slug> for String {
+    fn from(slug: Slug) -> Self {
+        slug.0
+    }
+}
+
+/// Error returned when a string which should be a slug isn't one
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidSlug {
+    input: String,
+}
+
+impl InvalidSlug {
+    /// The rejected string
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for InvalidSlug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not a valid slug, did you mean `{}`?",
+            self.input,
+            slugify(&self.input)
+        )
+    }
+}
+
+impl Error for InvalidSlug {}
+
+/// Parser of [`Slug`](struct.Slug.html) command line arguments for clap
+///
+/// The strict parser rejects values which are not slugs, the lenient one slugifies them.
+/// `value_parser!(Slug)` uses the strict parser.
+///
+/// ```rust
+/// use clap::{value_parser, Arg, Command};
+/// use slugmin::{Slug, SlugValueParser};
+///
+/// let command = Command::new("blog")
+///     .arg(Arg::new("slug").long("slug").value_parser(value_parser!(Slug)))
+///     .arg(Arg::new("title").long("title").value_parser(SlugValueParser::lenient()));
+///
+/// let matches = command
+///     .clone()
+///     .try_get_matches_from(["blog", "--slug", "my-post", "--title", "My Post!"])
+///     .unwrap();
+/// assert_eq!(matches.get_one::<Slug>("slug").unwrap().as_str(), "my-post");
+/// assert_eq!(matches.get_one::<Slug>("title").unwrap().as_str(), "my-post");
+///
+/// assert!(command.try_get_matches_from(["blog", "--slug", "My Post!"]).is_err());
+/// ```
+#[cfg(feature = "clap")]
+#[derive(Clone, Copy, Debug)]
+pub struct SlugValueParser {
+    strict: bool,
+}
+
+#[cfg(feature = "clap")]
+impl SlugValueParser {
+    /// Parser rejecting values which are not slugs
+    pub fn strict() -> Self {
+        SlugValueParser { strict: true }
+    }
+
+    /// Parser slugifying values
+    pub fn lenient() -> Self {
+        SlugValueParser { strict: false }
+    }
+}
+
+#[cfg(feature = "clap")]
+impl clap::builder::TypedValueParser for SlugValueParser {
+    type Value = Slug;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &std::ffi::OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        use clap::error::ErrorKind;
+
+        let value = value
+            .to_str()
+            .ok_or_else(|| clap::Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        if !self.strict {
+            return Ok(Slug::new(value));
+        }
+        Slug::parse(value).map_err(|err| {
+            let arg = arg.map_or_else(|| "...".to_string(), ToString::to_string);
+            let message = format!("invalid value for {}: {}\n", arg, err);
+            clap::Error::raw(ErrorKind::ValueValidation, message).with_cmd(cmd)
+        })
+    }
+}
+
+#[cfg(feature = "clap")]
+impl clap::builder::ValueParserFactory for Slug {
+    type Parser = SlugValueParser;
+
+    fn value_parser() -> Self::Parser {
+        SlugValueParser::strict()
+    }
+}
@@ -0,0 +1,41 @@
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Normalize `s` to a key for sorting, folding case and diacritics but keeping the script
+///
+/// Unlike a slug the key is not reduced to ascii: it's lowercased (unicode aware), NFKD
+/// normalized without the combining marks and then NFC normalized again, with whitespace runs
+/// replaced by a single space and trimmed. Letters without a decomposition, like `ß` or
+/// `æ`, stay as they are. Use it as a secondary key next to the original string.
+///
+/// ```rust
+/// use slugmin::sort_key;
+///
+/// assert_eq!(sort_key("café"), sort_key("cafe"));
+/// assert_eq!(sort_key("Resumé"), sort_key("resume"));
+/// assert_eq!(sort_key("  Crème   Brûlée "), "creme brulee");
+/// assert_eq!(sort_key("İstanbul"), "istanbul");
+/// assert_eq!(sort_key("Ελλάδα"), "ελλαδα");
+/// assert_eq!(sort_key("Straße Æsir ﬁne"), "straße æsir fine");
+/// assert_eq!(sort_key("한국어"), "한국어");
+/// ```
+pub fn sort_key<S: AsRef<str>>(s: S) -> String {
+    _sort_key(s.as_ref())
+}
+
+// avoid unnecessary monomorphizations
+fn _sort_key(s: &str) -> String {
+    let folded: String = s
+        .to_lowercase()
+        .nfkd()
+        .filter(|&c| !is_combining_mark(c))
+        .collect();
+    let mut key = String::with_capacity(folded.len());
+    for word in folded.split_whitespace() {
+        if !key.is_empty() {
+            key.push(' ');
+        }
+        key.extend(word.nfc());
+    }
+    key
+}
@@ -0,0 +1,224 @@
+use crate::slugify;
+
+/// Split any unicode string into the lowercase ascii words of its slug
+///
+/// The words are exactly those of `slugify`, so `slug_tokens(s).join("-") == slugify(s)`,
+/// which makes them a base for custom output styles.
+///
+/// ```rust
+/// use slugmin::{slug_tokens, slugify};
+///
+/// assert_eq!(slug_tokens("Hello, World! 123"), ["hello", "world", "123"]);
+/// assert_eq!(slug_tokens("Æúű--cool?"), ["aeuu", "cool"]);
+/// assert!(slug_tokens("!!!").is_empty());
+/// assert_eq!(slug_tokens("You & Me").join("-"), slugify("You & Me"));
+/// ```
+pub fn slug_tokens<S: AsRef<str>>(s: S) -> Vec<String> {
+    slugify(s)
+        .split('-')
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Slugify `s` without the `stop_words`, returning the slug and the removed tokens
+///
+/// Stop words are compared with the words of the slug after slugifying them too, so `The`
+/// removes `the`. The removed tokens are given in the order of `s`, stop words as words of
+/// the slug and whitespace separated parts of `s` without any slug, like `&`, as written.
+///
+/// ```rust
+/// use slugmin::slugify_without_stop_words;
+///
+/// let (slug, removed) = slugify_without_stop_words("The Lord of the Rings", &["the", "of"]);
+/// assert_eq!(slug, "lord-rings");
+/// assert_eq!(removed, ["the", "of", "the"]);
+///
+/// let (slug, removed) = slugify_without_stop_words("Tom & Jerry: A Tale", &["A"]);
+/// assert_eq!(slug, "tom-jerry-tale");
+/// assert_eq!(removed, ["&", "a"]);
+/// ```
+pub fn slugify_without_stop_words<S: AsRef<str>>(
+    s: S,
+    stop_words: &[&str],
+) -> (String, Vec<String>) {
+    _slugify_without_stop_words(s.as_ref(), stop_words)
+}
+
+// avoid unnecessary monomorphizations
+fn _slugify_without_stop_words(s: &str, stop_words: &[&str]) -> (String, Vec<String>) {
+    let stop_words: Vec<String> = stop_words.iter().map(slugify).collect();
+    let mut slug = String::with_capacity(s.len());
+    let mut removed = Vec::new();
+    for part in s.split_ascii_whitespace() {
+        let part_slug = slugify(part);
+        if part_slug.is_empty() {
+            removed.push(part.to_string());
+            continue;
+        }
+        for word in part_slug.split('-') {
+            if stop_words.iter().any(|stop_word| stop_word == word) {
+                removed.push(word.to_string());
+                continue;
+            }
+            if !slug.is_empty() {
+                slug.push('-');
+            }
+            slug.push_str(word);
+        }
+    }
+    (slug, removed)
+}
+
+/// Slugify every part and join the non empty ones with `separator`
+///
+/// Parts which are empty or have an empty slug are skipped, so there are never doubled,
+/// leading or trailing separators.
+///
+/// ```rust
+/// use slugmin::slug_join;
+///
+/// assert_eq!(slug_join(&["Blog", "", "My Post!"], '-'), "blog-my-post");
+/// assert_eq!(slug_join(&["Electronics", "???", "Smart Phones"], '/'), "electronics/smart-phones");
+/// assert_eq!(slug_join(&["", "!!!"], '-'), "");
+/// ```
+pub fn slug_join<S: AsRef<str>>(parts: &[S], separator: char) -> String {
+    let mut joined = String::new();
+    for part in parts {
+        let slug = slugify(part);
+        if slug.is_empty() {
+            continue;
+        }
+        if !joined.is_empty() {
+            joined.push(separator);
+        }
+        joined.push_str(&slug);
+    }
+    joined
+}
+
+/// Slugify every level of a category hierarchy and join them with `depth_sep`
+///
+/// Levels which are empty or have an empty slug are skipped. A slug never contains `--` or
+/// anything but `a-z`, `0-9` and `-`, so a `depth_sep` like `--` or `/` can't be confused with
+/// the `-` between the words of a level.
+///
+/// # Panics
+///
+/// When `depth_sep` could be a part of a slug, like `-` or `x`.
+///
+/// ```rust
+/// use slugmin::slugify_hierarchy;
+///
+/// let parts = ["Electronics", "Phones", "Smart Phones"];
+/// assert_eq!(slugify_hierarchy(&parts, "--"), "electronics--phones--smart-phones");
+/// assert_eq!(slugify_hierarchy(&parts, "."), "electronics.phones.smart-phones");
+/// assert_eq!(slugify_hierarchy(&["Home", "", "???", "Garden"], "--"), "home--garden");
+/// ```
+pub fn slugify_hierarchy<S: AsRef<str>>(parts: &[S], depth_sep: &str) -> String {
+    let in_slug = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-';
+    assert!(
+        depth_sep.contains("--") || !depth_sep.chars().all(in_slug),
+        "depth_sep {:?} can't be told apart from a slug",
+        depth_sep
+    );
+    let mut joined = String::new();
+    for part in parts {
+        let slug = slugify(part);
+        if slug.is_empty() {
+            continue;
+        }
+        if !joined.is_empty() {
+            joined.push_str(depth_sep);
+        }
+        joined.push_str(&slug);
+    }
+    joined
+}
+
+/// Replace the `from` separator of an existing slug with `to`, like `my-post` to `my_post`
+///
+/// Nothing else is slugified, so it works without the original title. A `to` already in the
+/// slug is treated as a separator too, it can't be told apart from a converted one, so runs of
+/// both collapse into a single `to`. Leading and trailing separators are removed.
+///
+/// ```rust
+/// use slugmin::reslug_separator;
+///
+/// assert_eq!(reslug_separator("my-post-2", '-', '_'), "my_post_2");
+/// assert_eq!(reslug_separator("my_post_2", '_', '-'), "my-post-2");
+/// assert_eq!(reslug_separator("snake_case-post", '-', '_'), "snake_case_post");
+/// assert_eq!(reslug_separator("-a-_b--", '-', '_'), "a_b");
+/// assert_eq!(reslug_separator("", '-', '_'), "");
+/// ```
+pub fn reslug_separator<S: AsRef<str>>(slug: S, from: char, to: char) -> String {
+    _reslug_separator(slug.as_ref(), from, to)
+}
+
+// avoid unnecessary monomorphizations
+fn _reslug_separator(slug: &str, from: char, to: char) -> String {
+    let mut result = String::with_capacity(slug.len());
+    for word in slug.split([from, to]) {
+        if word.is_empty() {
+            continue;
+        }
+        if !result.is_empty() {
+            result.push(to);
+        }
+        result.push_str(word);
+    }
+    result
+}
+
+/// Split the slug of `s` into slugs of at most `max` bytes, cutting only between words
+///
+/// Words are packed greedily into chunks, a word longer than `max` is split into pieces of
+/// `max` bytes. Every chunk is a valid slug on its own, without leading or trailing `-`.
+///
+/// # Panics
+///
+/// When `max` is 0.
+///
+/// ```rust
+/// use slugmin::slugify_chunks;
+///
+/// let chunks = slugify_chunks("The quick brown fox jumps over the lazy dog", 10);
+/// assert_eq!(chunks, ["the-quick", "brown-fox", "jumps-over", "the-lazy", "dog"]);
+/// for chunk in &chunks {
+///     assert!(chunk.len() <= 10);
+///     assert!(!chunk.starts_with('-') && !chunk.ends_with('-') && !chunk.contains("--"));
+/// }
+///
+/// assert_eq!(slugify_chunks("Supercalifragilistic day", 8), ["supercal", "ifragili", "stic-day"]);
+/// assert!(slugify_chunks("", 8).is_empty());
+/// ```
+pub fn slugify_chunks<S: AsRef<str>>(s: S, max: usize) -> Vec<String> {
+    assert!(max > 0, "chunks must have at least one byte");
+    let mut chunks = Vec::new();
+    let mut chunk = String::with_capacity(max);
+    for word in slug_tokens(s) {
+        let mut word = word.as_str();
+        while !word.is_empty() {
+            let needed = if chunk.is_empty() { 0 } else { 1 };
+            if chunk.len() + needed + word.len() <= max {
+                if needed == 1 {
+                    chunk.push('-');
+                }
+                chunk.push_str(word);
+                break;
+            }
+            if !chunk.is_empty() {
+                chunks.push(std::mem::replace(&mut chunk, String::with_capacity(max)));
+                continue;
+            }
+            // Word is too long even for an empty chunk, slugs are ascii so it can be cut anywhere
+            let (piece, rest) = word.split_at(max);
+            chunks.push(piece.to_string());
+            word = rest;
+        }
+    }
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+    chunks
+}
@@ -0,0 +1,75 @@
+use crate::slugify;
+
+/// Slugify `s`, appending `-2`, `-3`, ... until `exists` returns false for the slug
+///
+/// At most `max_attempts` suffixes are tried after the plain slug, then `None` is returned so
+/// the caller can fall back to something else, like a random or hash suffix.
+///
+/// ```rust
+/// use slugmin::try_slugify_unique;
+///
+/// let taken = ["my-post", "my-post-2"];
+/// let exists = |slug: &str| taken.contains(&slug);
+/// assert_eq!(try_slugify_unique("My Post", 5, exists), Some("my-post-3".to_string()));
+/// assert_eq!(try_slugify_unique("Other Post", 5, exists), Some("other-post".to_string()));
+/// assert_eq!(try_slugify_unique("My Post", 1, exists), None);
+/// assert_eq!(try_slugify_unique("My Post", 5, |_| true), None);
+/// ```
+pub fn try_slugify_unique<S: AsRef<str>, F: Fn(&str) -> bool>(
+    s: S,
+    max_attempts: usize,
+    exists: F,
+) -> Option<String> {
+    _try_slugify_unique(s.as_ref(), max_attempts, &exists)
+}
+
+// avoid unnecessary monomorphizations
+fn _try_slugify_unique(
+    s: &str,
+    max_attempts: usize,
+    exists: &dyn Fn(&str) -> bool,
+) -> Option<String> {
+    let slug = slugify(s);
+    if !exists(&slug) {
+        return Some(slug);
+    }
+    (2..max_attempts + 2)
+        .map(|n| {
+            if slug.is_empty() {
+                n.to_string()
+            } else {
+                format!("{}-{}", slug, n)
+            }
+        })
+        .find(|candidate| !exists(candidate))
+}
+
+/// Split a `-N` counter, like the one appended by `try_slugify_unique`, from the slug
+///
+/// Returns `(s, None)` when `s` doesn't end with `-` and a number. A slug which is only a
+/// number, like `2024`, has no base and so no suffix either. Numbers with leading zeros
+/// (`v-01`) or too large for `u32` are not counters.
+///
+/// ```rust
+/// use slugmin::split_slug_suffix;
+///
+/// assert_eq!(split_slug_suffix("my-post"), ("my-post", None));
+/// assert_eq!(split_slug_suffix("my-post-2"), ("my-post", Some(2)));
+/// assert_eq!(split_slug_suffix("2024"), ("2024", None));
+/// assert_eq!(split_slug_suffix("top-10-2"), ("top-10", Some(2)));
+/// assert_eq!(split_slug_suffix("v-01"), ("v-01", None));
+/// assert_eq!(split_slug_suffix("my-post-"), ("my-post-", None));
+/// ```
+pub fn split_slug_suffix(s: &str) -> (&str, Option<u32>) {
+    if let Some((base, suffix)) = s.rsplit_once('-') {
+        let is_counter = !base.is_empty()
+            && suffix.bytes().all(|b| b.is_ascii_digit())
+            && !suffix.starts_with('0');
+        if is_counter {
+            if let Ok(n) = suffix.parse() {
+                return (base, Some(n));
+            }
+        }
+    }
+    (s, None)
+}
@@ -0,0 +1,74 @@
+// Slugs are built from bytes which are assumed to be ascii, the debug assertions of the
+// unchecked conversions run here too
+
+mod common;
+
+use common::inputs;
+use slugmin::*;
+
+fn assert_ascii(name: &str, f: impl Fn(&str) -> String) {
+    for input in inputs() {
+        let slug = f(&input);
+        assert!(slug.is_ascii(), "{} gives {:?} for {:?}", name, slug, input);
+    }
+}
+
+#[test]
+fn slugs_are_ascii() {
+    assert_ascii("slugify", |s| slugify(s));
+    assert_ascii("slugify_or", |s| slugify_or(s, "untitled"));
+    assert_ascii("slugify_with_trailing_sep", |s| {
+        slugify_with_trailing_sep(s)
+    });
+    assert_ascii("slugify_bytes", |s| slugify_bytes(s));
+    assert_ascii("slugify_capped", |s| slugify_capped(s, 12));
+    assert_ascii("slugify_fixed", |s| slugify_fixed(s, 12, '_'));
+    assert_ascii("slugify_normal", |s| slugify_normal(s, false));
+    assert_ascii("slugify_normal leave_size", |s| slugify_normal(s, true));
+    assert_ascii("slugify_large", |s| slugify_large(s, 5));
+    assert_ascii("slugify_path", |s| slugify_path(s));
+    assert_ascii("slug_cache_key", |s| slug_cache_key(s));
+    assert_ascii("slugify_chunks", |s| slugify_chunks(s, 7).join("-"));
+    assert_ascii("slugify_without_stop_words", |s| {
+        slugify_without_stop_words(s, &["a"]).0
+    });
+    assert_ascii("slugify_with_protected", |s| {
+        slugify_with_protected(s, &["C++", "東"])
+    });
+    assert_ascii("slugify_django", |s| slugify_django(s, false));
+    assert_ascii("parameterize", |s| parameterize(s, '_', true));
+    assert_ascii("sanitize_title", |s| sanitize_title(s));
+    assert_ascii("wiki_anchor", |s| wiki_anchor(s));
+    assert_ascii("slugify_query_value", |s| slugify_query_value(s));
+    assert_ascii("slugify_camel", |s| slugify_camel(s));
+    assert_ascii("titleize", |s| titleize(s, &["ab"]));
+    assert_ascii("slugify_ci_safe", |s| slugify_ci_safe(s));
+    assert_ascii("slugify_handle", |s| slugify_handle(s));
+    assert_ascii("slugify_slack_channel", |s| slugify_slack_channel(s));
+}
+
+#[test]
+fn slugs_with_options_are_ascii() {
+    let options = [
+        SlugOptions::new(),
+        SlugOptions::normal(true),
+        SlugOptions::new().unmappable(Unmappable::PercentEncode),
+        SlugOptions::normal(false)
+            .unmappable(Unmappable::PercentEncode)
+            .max_length(10),
+        SlugOptions::new().unmappable(Unmappable::Replace("&".to_string())),
+        SlugOptions::new().override_char('東', "tō"),
+        SlugOptions::new().emoji(EmojiMode::Placeholder("ü".to_string())),
+        SlugOptions::new().leading_digit(LeadingDigit::Prefix('_')),
+        SlugOptions::new()
+            .keep_ip_addresses(true)
+            .keep_hex_colors(true),
+        SlugOptions::new().roman_numerals(true).kana_romaji(true),
+    ];
+    for options in options.iter() {
+        assert_ascii(&format!("{:?}", options), |s| slugify_with(s, options));
+    }
+    assert_ascii("slugify_with_fallback", |s| {
+        slugify_with_fallback(s, |_| Some("ö".to_string()))
+    });
+}
@@ -0,0 +1,24 @@
+// Inputs shared by the property tests
+
+const ALPHABET: &str =
+    "XIV 1.234,5 -_.:aZb9!&%#@'/\t\n½№éÜß東🎉\u{E000}ǅ\u{A0}\u{3000}\u{2028}\u{AD}\u{2060}עָ\u{200F}°±—〜";
+
+// Deterministic xorshift generated inputs, mixing ascii punctuation and unicode
+pub fn inputs() -> Vec<String> {
+    let alphabet: Vec<char> = ALPHABET.chars().collect();
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state as usize
+    };
+    (0..5000)
+        .map(|_| {
+            let len = next() % 70;
+            (0..len)
+                .map(|_| alphabet[next() % alphabet.len()])
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,34 @@
+// `#[derive(Slugify)]` on structs like the models of a web framework
+#![cfg(feature = "derive")]
+
+use slugmin::{slugify, Slugify};
+
+#[derive(Slugify)]
+struct Article<'a> {
+    title: &'a str,
+    #[slug(from = "title")]
+    slug: String,
+    #[slug(from = "title", max_length = 12)]
+    short_slug: String,
+    category: String,
+    #[slug(from = "category")]
+    category_slug: String,
+}
+
+#[test]
+fn fills_slug_fields() {
+    let mut article = Article {
+        title: "Æúű--cool? A Guide to Slugs",
+        slug: "stale".to_string(),
+        short_slug: String::new(),
+        category: "Rust & Web".to_string(),
+        category_slug: String::new(),
+    };
+    article.fill_slug();
+    article.fill_short_slug();
+    article.fill_category_slug();
+    assert_eq!(article.slug, slugify(article.title));
+    assert_eq!(article.slug, "aeuu-cool-a-guide-to-slugs");
+    assert_eq!(article.short_slug, "aeuu-cool-a");
+    assert_eq!(article.category_slug, "rust-web");
+}
@@ -0,0 +1,209 @@
+// Slugifying a slug again must not change it, checked on generated inputs
+
+mod common;
+
+use common::inputs;
+use slugmin::*;
+
+fn assert_idempotent(name: &str, f: impl Fn(&str) -> String) {
+    for input in inputs() {
+        let once = f(&input);
+        assert_eq!(f(&once), once, "{} is not idempotent for {:?}", name, input);
+    }
+}
+
+#[test]
+fn slugify_is_idempotent() {
+    assert_idempotent("slugify", |s| slugify(s));
+    assert_idempotent("slugify_or", |s| slugify_or(s, "untitled"));
+    assert_idempotent("slugify_ci_safe", |s| slugify_ci_safe(s));
+    assert_idempotent("slug_and_title", |s| slug_and_title(s).0);
+    assert_idempotent("slugify_normal", |s| slugify_normal(s, false));
+    assert_idempotent("slugify_normal leave_size", |s| slugify_normal(s, true));
+    assert_idempotent("Slug::new", |s| Slug::new(s).into_string());
+    assert_idempotent("slug_join", |s| slug_join(&slug_tokens(s), '-'));
+    assert_idempotent("slugify_chunks", |s| slugify_chunks(s, 7).join("-"));
+    assert_idempotent("slugify_without_stop_words", |s| {
+        slugify_without_stop_words(s, &["a", "The"]).0
+    });
+    for &preset in [Preset::Slug, Preset::DnsLabel, Preset::Filename].iter() {
+        assert_idempotent(&format!("{:?}", preset), |s| slugify_preset(s, preset));
+    }
+    static MAP: SlugMap = slug_map! { 'é' => "e", '&' => "-and-", '東' => "to" };
+    assert_idempotent("slugify_with_map", |s| slugify_with_map(s, &MAP));
+}
+
+#[test]
+fn options_are_idempotent() {
+    let options = [
+        SlugOptions::new().unify_separators(&['.', ':']),
+        SlugOptions::normal(false).unify_separators(&['_']),
+        SlugOptions::new().collapse_separators(false),
+        SlugOptions::new().max_length(12).min_last_word_len(3),
+        SlugOptions::new().max_length(9).keep_trailing_number(true),
+        SlugOptions::new()
+            .max_length(12)
+            .keep_decimals(true)
+            .keep_ip_addresses(true),
+        SlugOptions::normal(true).max_length(20),
+        SlugOptions::new().internal_chars(&['.', '+', '\'']),
+        SlugOptions::new().keep_underscore(true),
+        SlugOptions::new().output_alphabet(&['a', 'b', 'x', '1', '2']),
+        SlugOptions::new().keep_underscore(true).max_length(10),
+        SlugOptions::normal(false).internal_chars(&['!']),
+        SlugOptions::new().unmappable(Unmappable::Delete),
+        SlugOptions::new().unmappable(Unmappable::Replace("and".to_string())),
+        SlugOptions::normal(true).trim_chars(&['.', '_', '-', ' ']),
+        SlugOptions::new().keep_decimals(true),
+        SlugOptions::new()
+            .decimal_separator(',')
+            .grouping_separator('.'),
+        SlugOptions::new()
+            .decimal_separator(',')
+            .grouping_separator('.')
+            .keep_decimals(true),
+        SlugOptions::new().keep_hex_colors(true),
+        SlugOptions::new().keep_ip_addresses(true),
+        SlugOptions::normal(true).keep_ip_addresses(true),
+        SlugOptions::normal(true).roman_numerals(true),
+        SlugOptions::normal(false).max_dots(2),
+        SlugOptions::normal(false)
+            .max_dots(0)
+            .collapse_dot_space(true),
+        SlugOptions::normal(true).collapse_dot_space(true),
+        SlugOptions::new().dedupe_repeats(true),
+        SlugOptions::new().dedupe_words(true),
+        SlugOptions::new()
+            .dedupe_words(true)
+            .keep_decimals(true)
+            .keep_ip_addresses(true),
+        SlugOptions::new().abbreviate_words(3),
+        SlugOptions::new().max_separators(2).max_length(10),
+        SlugOptions::new()
+            .max_separators(1)
+            .keep_decimals(true)
+            .keep_ip_addresses(true),
+        SlugOptions::normal(true).max_separators(1),
+        SlugOptions::new().latin_strategy(LatinStrategy::StripMarks),
+        SlugOptions::normal(true).latin_strategy(LatinStrategy::StripMarks),
+        SlugOptions::normal(true).abbreviate_words(1).max_length(10),
+        SlugOptions::normal(true).dedupe_words(true).max_length(12),
+        SlugOptions::normal(true)
+            .dedupe_repeats(true)
+            .max_length(12),
+        SlugOptions::new().measurement_symbols(true),
+        SlugOptions::normal(true).measurement_symbols(true),
+        SlugOptions::new().colon_in_time('h'),
+        SlugOptions::new().colon_in_time('.'),
+        SlugOptions::normal(false).colon_in_time('_'),
+        SlugOptions::new().punct_separator('_'),
+        SlugOptions::new().word_separator('_').punct_separator('.'),
+        SlugOptions::new()
+            .punct_separator('_')
+            .collapse_separators(false),
+        SlugOptions::normal(false).punct_separator('-'),
+        SlugOptions::new().punct_separator('~').max_length(12),
+        SlugOptions::new().emoji(EmojiMode::Placeholder("emoji".to_string())),
+        SlugOptions::new().at_sign(AtSign::Drop),
+        SlugOptions::normal(true).at_sign(AtSign::Word("at".to_string())),
+        SlugOptions::normal(true).emoji(EmojiMode::Delete),
+        SlugOptions::new().strip_extensions(&["ab", "z"]),
+        SlugOptions::new().strip_list_prefix(true),
+        SlugOptions::normal(true).strip_extensions(&["5", "b"]),
+        SlugOptions::new().mark_questions("q"),
+        SlugOptions::normal(true).mark_questions("Faq?"),
+        SlugOptions::new().leading_digit(LeadingDigit::Strip),
+        SlugOptions::new().leading_digit(LeadingDigit::Prefix('_')),
+        SlugOptions::normal(false)
+            .leading_digit(LeadingDigit::Prefix('n'))
+            .max_length(10),
+    ];
+    for options in options.iter() {
+        assert_idempotent(&format!("{:?}", options), |s| slugify_with(s, options));
+    }
+    assert_idempotent("slugify_with_filter", |s| {
+        slugify_with_filter(s, |c| c == '.', '_', false)
+    });
+    assert_idempotent("slugify_with_filter leave_size", |s| {
+        slugify_with_filter(s, |c| c.is_ascii_alphanumeric(), '-', true)
+    });
+    assert_idempotent("slugify_with_fallback", |s| {
+        slugify_with_fallback(s, |_| Some("x".to_string()))
+    });
+}
+
+#[test]
+fn case_styles_are_idempotent() {
+    assert_idempotent("slugify_camel", |s| slugify_camel(s));
+    assert_idempotent("slugify_train_case", |s| slugify_train_case(s, &["ab"]));
+    assert_idempotent("slugify_sentence_case", |s| slugify_sentence_case(s));
+    assert_idempotent("titleize", |s| titleize(s, &["ab"]));
+    // Camel, Pascal and Train case join the words in a way `restyle` doesn't split again
+    for &style in [
+        CaseStyle::Snake,
+        CaseStyle::ScreamingSnake,
+        CaseStyle::Kebab,
+    ]
+    .iter()
+    {
+        assert_idempotent(&format!("restyle {:?}", style), |s| restyle(s, style));
+    }
+}
+
+#[test]
+fn paths_are_idempotent() {
+    assert_idempotent("slugify_path", |s| slugify_path(s));
+    assert_idempotent("slugify_hierarchy", |s| {
+        slugify_hierarchy(&s.split('/').collect::<Vec<_>>(), "/")
+    });
+    assert_idempotent("reslug_separator", |s| reslug_separator(s, '-', '_'));
+}
+
+#[test]
+fn anchors_are_idempotent() {
+    assert_idempotent("github_anchor", |s| github_anchor(s));
+    assert_idempotent("pandoc_identifier", |s| pandoc_identifier(s));
+}
+
+#[test]
+fn compat_functions_are_idempotent() {
+    assert_idempotent("slugify_django", |s| slugify_django(s, false));
+    assert_idempotent("slugify_django unicode", |s| slugify_django(s, true));
+    assert_idempotent("parameterize", |s| parameterize(s, '-', false));
+    assert_idempotent("parameterize preserve_case", |s| parameterize(s, '_', true));
+    assert_idempotent("sanitize_title", |s| sanitize_title(s));
+}
+
+#[test]
+fn handles_are_idempotent() {
+    assert_idempotent("slugify_fedi_username", |s| slugify_fedi_username(s));
+    assert_idempotent("slugify_irc_channel", |s| slugify_irc_channel(s));
+    assert_idempotent("slugify_slack_channel", |s| slugify_slack_channel(s));
+    assert_idempotent("slugify_telegram_username", |s| {
+        slugify_telegram_username(s)
+    });
+    assert_idempotent("slugify_handle", |s| slugify_handle(s));
+}
+
+// `wiki_anchor`, `slugify_with_protected` and `Unmappable::PercentEncode` encode characters
+// in a way which is not recognized again, they are documented as not idempotent
+#[test]
+fn encodings_are_not_idempotent() {
+    assert_eq!(wiki_anchor(wiki_anchor("100%")), "100%2525");
+    let once = slugify_with_protected("Learn C# Today", &["C#"]);
+    assert_eq!(once, "learn-C%23-today");
+    assert_eq!(slugify_with_protected(&once, &["C#"]), "learn-c-23-today");
+    let options = SlugOptions::new().unmappable(Unmappable::PercentEncode);
+    assert_eq!(
+        slugify_with(slugify_with("a!", &options), &options),
+        "a%2521"
+    );
+}
+
+// `slugify_last_segment` slugifies the segment before a trailing `/`, which is the part kept
+// unchanged the first time when the last segment has an empty slug
+#[test]
+fn last_segment_is_not_idempotent() {
+    assert_eq!(slugify_last_segment("/Blog/!!"), "/Blog/");
+    assert_eq!(slugify_last_segment("/Blog/"), "/blog/");
+}
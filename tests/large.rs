@@ -0,0 +1,22 @@
+// `slugify_large` must give exactly the slug of the whole input, wherever it is split
+
+mod common;
+
+use common::inputs;
+use slugmin::*;
+
+#[test]
+fn slugify_large_matches_slugify() {
+    let text = inputs().join("");
+    for &chunk_size in [1, 7, 100, 4096, text.len()].iter() {
+        assert_eq!(
+            slugify_large(&text, chunk_size),
+            slugify(&text),
+            "chunk_size {}",
+            chunk_size
+        );
+    }
+    for input in inputs().iter().take(500) {
+        assert_eq!(slugify_large(input, 3), slugify(input), "{:?}", input);
+    }
+}
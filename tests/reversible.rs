@@ -0,0 +1,26 @@
+// `slugify_reversible` must make the same slug as `slugify`, and ascii titles must come back
+// exactly from `reconstruct`
+
+mod common;
+
+use common::inputs;
+use slugmin::*;
+
+#[test]
+fn slugify_reversible_matches_slugify() {
+    for input in inputs() {
+        let (slug, _) = slugify_reversible(&input);
+        assert_eq!(slug, slugify(&input), "{:?}", input);
+    }
+}
+
+#[test]
+fn reconstruct_recovers_ascii_titles() {
+    for input in inputs().into_iter().filter(|input| input.is_ascii()) {
+        let (slug, meta) = slugify_reversible(&input);
+        assert_eq!(reconstruct(&slug, &meta), input);
+    }
+    let title = "My  Test\tString!!!1!1";
+    let (slug, meta) = slugify_reversible(title);
+    assert_eq!(reconstruct(&slug, &meta), title);
+}
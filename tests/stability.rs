@@ -0,0 +1,46 @@
+// Surrounding whitespace and terminal punctuation must not change a slug, so anchors stay
+// the same when a heading is edited
+
+mod common;
+
+use common::inputs;
+use slugmin::*;
+
+fn assert_stable(name: &str, f: impl Fn(&str) -> String) {
+    for input in inputs() {
+        let slug = f(&input);
+        assert_eq!(
+            f(input.trim()),
+            slug,
+            "{} changes when {:?} is trimmed",
+            name,
+            input
+        );
+        let edited = format!(" \t{}!!! \n", input);
+        assert_eq!(
+            f(&edited),
+            slug,
+            "{} changes when {:?} is edited",
+            name,
+            input
+        );
+        for end in ["!", "?", ".", "...", ":"].iter() {
+            let edited = format!("{}{}", input, end);
+            assert_eq!(
+                f(&edited),
+                slug,
+                "{} changes when {:?} ends with {:?}",
+                name,
+                input,
+                end
+            );
+        }
+    }
+}
+
+#[test]
+fn slugify_is_stable() {
+    assert_stable("slugify", |s| slugify(s));
+    assert_stable("slugify_with", |s| slugify_with(s, &SlugOptions::new()));
+    assert_stable("slug_len", |s| slug_len(s).to_string());
+}
@@ -0,0 +1,53 @@
+// Functions which stream the slug without allocating it must agree with `slugify`
+
+mod common;
+
+use common::inputs;
+use slugmin::*;
+
+#[test]
+fn slug_len_matches_slugify() {
+    for input in inputs() {
+        assert_eq!(slug_len(&input), slugify(&input).len(), "{:?}", input);
+    }
+}
+
+#[test]
+fn slug_eq_bounded_matches_slugify() {
+    for input in inputs() {
+        let slug = slugify(&input);
+        for max in [0, 1, 5, 12, 64] {
+            let cut = slug[..slug.len().min(max)].trim_end_matches('-');
+            assert!(
+                slug_eq_bounded(&input, cut, max),
+                "{:?} cut to {}",
+                input,
+                max
+            );
+            let longer = format!("{}x", cut);
+            assert!(
+                !slug_eq_bounded(&input, &longer, max),
+                "{:?} cut to {}",
+                input,
+                max
+            );
+        }
+    }
+}
+
+#[test]
+fn slugify_capped_matches_slugify() {
+    for input in inputs() {
+        let slug = slugify(&input);
+        for max in [0, 1, 5, 12, 64] {
+            let cut = slug[..slug.len().min(max)].trim_end_matches('-');
+            assert_eq!(
+                slugify_capped(&input, max),
+                cut,
+                "{:?} cut to {}",
+                input,
+                max
+            );
+        }
+    }
+}